@@ -0,0 +1,216 @@
+# German texts, missing messages are shown in English.
+
+language-name = Deutsch
+game-title = Rust Minesweeper
+
+## Shared
+
+hint-back = { $key } drücken, um zurückzukehren
+percent = { $value } %
+on = An
+off = Aus
+
+## Main menu and pause menu
+
+menu-new-game = Neues Spiel
+menu-continue = Fortsetzen
+menu-daily = Tägliche Herausforderung
+menu-puzzles = Rätsel
+menu-statistics = Statistiken
+menu-settings = Einstellungen
+menu-main-menu = Hauptmenü
+menu-quit = Beenden
+pause-title = Pausiert
+pause-subtitle = Zeit: { $time } | { $key } drücken, um fortzufahren
+
+## Statistics
+
+statistics-title = Statistiken
+statistics-played = Gespielte Partien
+statistics-won = Gewonnene Partien
+statistics-win-rate = Gewinnrate
+statistics-current-streak = Aktuelle Serie
+statistics-best-streak = Beste Serie
+statistics-best-times = Bestzeiten
+statistics-no-wins = Noch kein Spielfeld gewonnen
+statistics-board = { $width }x{ $height }, { $mines ->
+    [one] { $mines } Mine
+   *[other] { $mines } Minen
+}
+
+## Puzzles
+
+puzzles-title = Rätsel
+puzzles-none = Keine Rätsel gefunden
+puzzles-solved = Gelöst
+puzzle-mines-left = Verbleibende Minen: { $count }
+
+## Daily challenge
+
+daily-title = Tägliche Herausforderung - { $month } { $year }
+daily-lost = Verloren
+daily-done = Die heutige Herausforderung ist erledigt. Komm morgen wieder!
+daily-play = { $key } drücken, um die heutige Herausforderung zu spielen
+daily-hint = { $previous } / { $next }: Monat wechseln | { $back }: zurück
+month-1 = Januar
+month-2 = Februar
+month-3 = März
+month-4 = April
+month-5 = Mai
+month-6 = Juni
+month-7 = Juli
+month-8 = August
+month-9 = September
+month-10 = Oktober
+month-11 = November
+month-12 = Dezember
+weekday-1 = Mo
+weekday-2 = Di
+weekday-3 = Mi
+weekday-4 = Do
+weekday-5 = Fr
+weekday-6 = Sa
+weekday-7 = So
+
+## Board editor
+
+editor-help = { $mine }: Mine | { $cover }: aufdecken / Flagge | { $save }: speichern | { $clear }: leeren | { $back }: zurück
+editor-status = Minen: { $mines } | Aufgedeckt: { $revealed } | Lösbar: { $solvable ->
+        [yes] ja
+       *[no] nein
+    }
+
+## Controls
+
+controls-title = Steuerung
+controls-reset = Standard wiederherstellen
+controls-capture =
+    Taste oder Maustaste für '{ $action }' drücken
+    Escape bricht ab, { $key } entfernt alle Belegungen
+controls-conflict = { $binding } ist sowohl '{ $first }' als auch '{ $second }' zugewiesen
+controls-hint = Aktion anklicken, um eine Belegung hinzuzufügen. { $key } drücken, um zurückzukehren
+action-uncover = Aufdecken
+action-flag = Flagge
+action-chord = Akkord
+action-cursor-left = Cursor nach links
+action-cursor-right = Cursor nach rechts
+action-cursor-up = Cursor nach oben
+action-cursor-down = Cursor nach unten
+action-next-unresolved = Nächstes offenes Feld
+action-pause = Pause
+action-clear-board = Spielfeld leeren
+action-export-board = Spielfeld exportieren
+action-describe-board = Spielfeld beschreiben
+action-restart = Neues Spielfeld
+action-open-puzzles = Rätsel
+action-open-editor = Spielfeld-Editor
+action-open-daily = Tägliche Herausforderung
+action-open-controls = Steuerung
+action-back = Zurück
+action-previous-month = Vorheriger Monat
+action-next-month = Nächster Monat
+action-start-daily = Heutige Herausforderung spielen
+action-toggle-mine = Editor: Mine umschalten
+action-toggle-cover = Editor: aufdecken / Flagge
+action-save-level = Editor: speichern
+action-clear-level = Editor: leeren
+action-clear-bindings = Belegungen entfernen
+action-pan-camera = Verschieben (halten)
+action-zoom-in = Vergrößern
+action-zoom-out = Verkleinern
+action-zoom-to-fit = Einpassen
+action-toggle-vsync = VSync umschalten
+action-toggle-fullscreen = Vollbild umschalten
+action-next-monitor = Nächster Monitor
+action-next-theme = Nächstes Design
+action-toggle-animations = Animationen umschalten
+action-toggle-mute = Stummschaltung umschalten
+
+## Settings
+
+settings-title = Einstellungen
+settings-board = Spielfeld
+settings-display = Anzeige
+settings-gameplay = Spiel
+settings-audio = Audio
+settings-controls = Steuerung
+settings-hint = Größe und Minen gelten ab dem nächsten Spiel. Umschalt-Klick für größere Schritte
+setting-difficulty = Schwierigkeit
+setting-width = Breite
+setting-height = Höhe
+setting-mines = Minen
+setting-safe-start = Sicherer Start
+setting-tile-padding = Feldabstand
+setting-tile-size = Feldgröße
+setting-vsync = VSync
+setting-fullscreen = Vollbild
+setting-theme = Design
+setting-language = Sprache
+setting-number-colors = Zahlenfarben
+setting-number-shapes = Zahlenformen
+setting-question-marks = Fragezeichen
+setting-chording = Akkorde
+setting-animations = Animationen
+setting-master-volume = Gesamtlautstärke
+setting-effects-volume = Effektlautstärke
+setting-music-volume = Musiklautstärke
+setting-mute = Stumm
+difficulty-beginner = Anfänger
+difficulty-intermediate = Fortgeschritten
+difficulty-expert = Experte
+difficulty-custom = Benutzerdefiniert
+tile-size-adaptive = Automatisch
+pixels = { $value } px
+chord-mode-uncover = Aufdecken
+chord-mode-chord-button = Nur Taste
+chord-mode-disabled = Aus
+number-palette-theme = Design
+number-palette-deuteranopia = Deuteranopie
+number-palette-protanopia = Protanopie
+number-palette-tritanopia = Tritanopie
+number-palette-monochrome = Monochrom
+number-decoration-dice = Würfel
+number-decoration-glyphs = Symbole
+
+## Gamepad and touch screen hints
+
+gamepad-uncover = Aufdecken
+gamepad-flag = Flagge
+gamepad-chord = Akkord
+gamepad-move = Bewegen
+gamepad-pan = Verschieben
+gamepad-zoom = Zoom
+gamepad-menu = Menü
+touch-mode-uncover = Tippen: aufdecken
+touch-mode-flag = Tippen: Flagge
+
+## Screen reader
+
+a11y-board = Spielfeld mit { $width } mal { $height } Feldern, { $mines ->
+        [one] { $mines } Mine
+       *[other] { $mines } Minen
+    }
+a11y-tile = Spalte { $column }, Zeile { $row }, { $state ->
+        [flagged] markiert
+        [question-mark] Fragezeichen
+        [mine] Mine
+        [empty] leer
+        [number] { $count ->
+            [one] { $count } Mine in der Nähe
+           *[other] { $count } Minen in der Nähe
+        }
+       *[covered] verdeckt
+    }
+a11y-cascade = { $count ->
+        [one] { $count } Feld aufgedeckt
+       *[other] { $count } Felder aufgedeckt
+    }
+a11y-explosion = Mine in Spalte { $column }, Zeile { $row } explodiert. Spiel vorbei
+a11y-victory = Spielfeld in { $time } geräumt
+a11y-summary = { $covered ->
+        [one] { $covered } verdecktes Feld
+       *[other] { $covered } verdeckte Felder
+    }, { $flags ->
+        [one] { $flags } Flagge
+       *[other] { $flags } Flaggen
+    } bei { $mines } Minen, Zeit { $time }
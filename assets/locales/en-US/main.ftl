@@ -0,0 +1,220 @@
+# English texts, every other language falls back to them for missing messages.
+# Numbers passed to messages select the plural form, see https://projectfluent.org/fluent/guide/
+
+language-name = English
+game-title = Rust Minesweeper
+
+## Shared
+
+hint-back = Press { $key } to go back
+percent = { $value }%
+on = On
+off = Off
+
+## Main menu and pause menu
+
+menu-new-game = New game
+menu-continue = Continue
+menu-daily = Daily challenge
+menu-puzzles = Puzzles
+menu-statistics = Statistics
+menu-settings = Settings
+menu-main-menu = Main menu
+menu-quit = Quit
+pause-title = Paused
+pause-subtitle = Time: { $time } | Press { $key } to continue
+
+## Statistics
+
+statistics-title = Statistics
+statistics-played = Games played
+statistics-won = Games won
+statistics-win-rate = Win rate
+statistics-current-streak = Current streak
+statistics-best-streak = Best streak
+statistics-best-times = Best times
+statistics-no-wins = No board won yet
+statistics-board = { $width }x{ $height }, { $mines ->
+    [one] { $mines } mine
+   *[other] { $mines } mines
+}
+
+## Puzzles
+
+puzzles-title = Puzzles
+puzzles-none = No puzzles found
+puzzles-solved = Solved
+puzzle-mines-left = Mines left: { $count }
+
+## Daily challenge
+
+daily-title = Daily challenge - { $month } { $year }
+daily-lost = Lost
+daily-done = Today's challenge is done. Come back tomorrow!
+daily-play = Press { $key } to play today's challenge
+daily-hint = { $previous } / { $next }: change month | { $back }: go back
+month-1 = January
+month-2 = February
+month-3 = March
+month-4 = April
+month-5 = May
+month-6 = June
+month-7 = July
+month-8 = August
+month-9 = September
+month-10 = October
+month-11 = November
+month-12 = December
+# Weekdays, starting on Monday
+weekday-1 = Mo
+weekday-2 = Tu
+weekday-3 = We
+weekday-4 = Th
+weekday-5 = Fr
+weekday-6 = Sa
+weekday-7 = Su
+
+## Board editor
+
+editor-help = { $mine }: mine | { $cover }: reveal / flag | { $save }: save | { $clear }: clear | { $back }: back
+# $solvable is either "yes" or "no"
+editor-status = Mines: { $mines } | Revealed: { $revealed } | Solvable: { $solvable ->
+        [yes] yes
+       *[no] no
+    }
+
+## Controls
+
+controls-title = Controls
+controls-reset = Reset to defaults
+controls-capture =
+    Press a key or mouse button for '{ $action }'
+    Escape cancels, { $key } removes all bindings
+controls-conflict = { $binding } is bound to both '{ $first }' and '{ $second }'
+controls-hint = Click an action to add a binding. Press { $key } to go back
+action-uncover = Uncover
+action-flag = Flag
+action-chord = Chord
+action-cursor-left = Cursor left
+action-cursor-right = Cursor right
+action-cursor-up = Cursor up
+action-cursor-down = Cursor down
+action-next-unresolved = Next unresolved tile
+action-pause = Pause
+action-clear-board = Clear the board
+action-export-board = Export the board
+action-describe-board = Describe the board
+action-restart = New board
+action-open-puzzles = Puzzles
+action-open-editor = Board editor
+action-open-daily = Daily challenge
+action-open-controls = Controls
+action-back = Back
+action-previous-month = Previous month
+action-next-month = Next month
+action-start-daily = Play today's challenge
+action-toggle-mine = Editor: toggle mine
+action-toggle-cover = Editor: reveal / flag
+action-save-level = Editor: save
+action-clear-level = Editor: clear
+action-clear-bindings = Remove bindings
+action-pan-camera = Pan (hold)
+action-zoom-in = Zoom in
+action-zoom-out = Zoom out
+action-zoom-to-fit = Zoom to fit
+action-toggle-vsync = Toggle vsync
+action-toggle-fullscreen = Toggle fullscreen
+action-next-monitor = Next monitor
+action-next-theme = Next theme
+action-toggle-animations = Toggle animations
+action-toggle-mute = Toggle mute
+
+## Settings
+
+settings-title = Settings
+settings-board = Board
+settings-display = Display
+settings-gameplay = Gameplay
+settings-audio = Audio
+settings-controls = Controls
+settings-hint = Board size and mines apply to the next new game. Shift-click for bigger steps
+setting-difficulty = Difficulty
+setting-width = Width
+setting-height = Height
+setting-mines = Mines
+setting-safe-start = Safe start
+setting-tile-padding = Tile padding
+setting-tile-size = Tile size
+setting-vsync = VSync
+setting-fullscreen = Fullscreen
+setting-theme = Theme
+setting-language = Language
+setting-number-colors = Number colors
+setting-number-shapes = Number shapes
+setting-question-marks = Question marks
+setting-chording = Chording
+setting-animations = Animations
+setting-master-volume = Master volume
+setting-effects-volume = Effects volume
+setting-music-volume = Music volume
+setting-mute = Mute
+difficulty-beginner = Beginner
+difficulty-intermediate = Intermediate
+difficulty-expert = Expert
+difficulty-custom = Custom
+tile-size-adaptive = Adaptive
+pixels = { $value } px
+chord-mode-uncover = Uncover
+chord-mode-chord-button = Button only
+chord-mode-disabled = Off
+number-palette-theme = Theme
+number-palette-deuteranopia = Deuteranopia
+number-palette-protanopia = Protanopia
+number-palette-tritanopia = Tritanopia
+number-palette-monochrome = Monochrome
+number-decoration-dice = Dice
+number-decoration-glyphs = Glyphs
+
+## Gamepad and touch screen hints
+
+gamepad-uncover = Uncover
+gamepad-flag = Flag
+gamepad-chord = Chord
+gamepad-move = Move
+gamepad-pan = Pan
+gamepad-zoom = Zoom
+gamepad-menu = Menu
+touch-mode-uncover = Tap: uncover
+touch-mode-flag = Tap: flag
+
+## Screen reader
+
+a11y-board = Board of { $width } by { $height } tiles, { $mines ->
+        [one] { $mines } mine
+       *[other] { $mines } mines
+    }
+# $state is covered, flagged, question-mark, mine, number or empty, $count is the number
+a11y-tile = Column { $column }, row { $row }, { $state ->
+        [flagged] flagged
+        [question-mark] question mark
+        [mine] mine
+        [empty] empty
+        [number] { $count ->
+            [one] { $count } mine around
+           *[other] { $count } mines around
+        }
+       *[covered] covered
+    }
+a11y-cascade = { $count ->
+        [one] { $count } tile uncovered
+       *[other] { $count } tiles uncovered
+    }
+a11y-explosion = Mine exploded at column { $column }, row { $row }. Game over
+a11y-victory = Board cleared in { $time }
+a11y-summary = { $covered ->
+        [one] { $covered } covered tile
+       *[other] { $covered } covered tiles
+    }, { $flags ->
+        [one] { $flags } flag
+       *[other] { $flags } flags
+    } for { $mines } mines, time { $time }
@@ -0,0 +1,198 @@
+# Japanese texts, missing messages are shown in English. Japanese has a single plural form.
+
+language-name = 日本語
+game-title = Rust マインスイーパー
+
+## Shared
+
+hint-back = { $key } で戻る
+percent = { $value }%
+on = オン
+off = オフ
+
+## Main menu and pause menu
+
+menu-new-game = 新しいゲーム
+menu-continue = 続ける
+menu-daily = デイリーチャレンジ
+menu-puzzles = パズル
+menu-statistics = 統計
+menu-settings = 設定
+menu-main-menu = メインメニュー
+menu-quit = 終了
+pause-title = 一時停止
+pause-subtitle = 時間: { $time } | { $key } で再開
+
+## Statistics
+
+statistics-title = 統計
+statistics-played = プレイ回数
+statistics-won = 勝利数
+statistics-win-rate = 勝率
+statistics-current-streak = 現在の連勝
+statistics-best-streak = 最高連勝
+statistics-best-times = ベストタイム
+statistics-no-wins = まだ勝利した盤面はありません
+statistics-board = { $width }x{ $height }、地雷 { $mines } 個
+
+## Puzzles
+
+puzzles-title = パズル
+puzzles-none = パズルが見つかりません
+puzzles-solved = 解決済み
+puzzle-mines-left = 残りの地雷: { $count }
+
+## Daily challenge
+
+daily-title = デイリーチャレンジ - { $year }年 { $month }
+daily-lost = 失敗
+daily-done = 今日のチャレンジは完了しました。また明日!
+daily-play = { $key } で今日のチャレンジを開始
+daily-hint = { $previous } / { $next }: 月を切り替え | { $back }: 戻る
+month-1 = 1月
+month-2 = 2月
+month-3 = 3月
+month-4 = 4月
+month-5 = 5月
+month-6 = 6月
+month-7 = 7月
+month-8 = 8月
+month-9 = 9月
+month-10 = 10月
+month-11 = 11月
+month-12 = 12月
+weekday-1 = 月
+weekday-2 = 火
+weekday-3 = 水
+weekday-4 = 木
+weekday-5 = 金
+weekday-6 = 土
+weekday-7 = 日
+
+## Board editor
+
+editor-help = { $mine }: 地雷 | { $cover }: 開く / 旗 | { $save }: 保存 | { $clear }: クリア | { $back }: 戻る
+editor-status = 地雷: { $mines } | 開いたマス: { $revealed } | 解ける: { $solvable ->
+        [yes] はい
+       *[no] いいえ
+    }
+
+## Controls
+
+controls-title = 操作設定
+controls-reset = 初期設定に戻す
+controls-capture =
+    「{ $action }」に割り当てるキーかマウスボタンを押してください
+    Escape でキャンセル、{ $key } で割り当てをすべて削除
+controls-conflict = { $binding } は「{ $first }」と「{ $second }」の両方に割り当てられています
+controls-hint = アクションをクリックして割り当てを追加。{ $key } で戻る
+action-uncover = 開く
+action-flag = 旗
+action-chord = 同時開き
+action-cursor-left = カーソル左
+action-cursor-right = カーソル右
+action-cursor-up = カーソル上
+action-cursor-down = カーソル下
+action-next-unresolved = 次の未解決マス
+action-pause = 一時停止
+action-clear-board = 盤面をクリア
+action-export-board = 盤面をエクスポート
+action-describe-board = 盤面を読み上げ
+action-restart = 新しい盤面
+action-open-puzzles = パズル
+action-open-editor = 盤面エディター
+action-open-daily = デイリーチャレンジ
+action-open-controls = 操作設定
+action-back = 戻る
+action-previous-month = 前の月
+action-next-month = 次の月
+action-start-daily = 今日のチャレンジを開始
+action-toggle-mine = エディター: 地雷の切り替え
+action-toggle-cover = エディター: 開く / 旗
+action-save-level = エディター: 保存
+action-clear-level = エディター: クリア
+action-clear-bindings = 割り当てを削除
+action-pan-camera = 移動 (長押し)
+action-zoom-in = 拡大
+action-zoom-out = 縮小
+action-zoom-to-fit = 全体表示
+action-toggle-vsync = VSync 切り替え
+action-toggle-fullscreen = フルスクリーン切り替え
+action-next-monitor = 次のモニター
+action-next-theme = 次のテーマ
+action-toggle-animations = アニメーション切り替え
+action-toggle-mute = ミュート切り替え
+
+## Settings
+
+settings-title = 設定
+settings-board = 盤面
+settings-display = 表示
+settings-gameplay = ゲームプレイ
+settings-audio = サウンド
+settings-controls = 操作設定
+settings-hint = 盤面サイズと地雷数は次のゲームから反映されます。Shift クリックで大きく変更
+setting-difficulty = 難易度
+setting-width = 幅
+setting-height = 高さ
+setting-mines = 地雷
+setting-safe-start = 安全なスタート
+setting-tile-padding = マスの間隔
+setting-tile-size = マスのサイズ
+setting-vsync = VSync
+setting-fullscreen = フルスクリーン
+setting-theme = テーマ
+setting-language = 言語
+setting-number-colors = 数字の色
+setting-number-shapes = 数字の形
+setting-question-marks = ?マーク
+setting-chording = 同時開き
+setting-animations = アニメーション
+setting-master-volume = 全体音量
+setting-effects-volume = 効果音量
+setting-music-volume = 音楽音量
+setting-mute = ミュート
+difficulty-beginner = 初級
+difficulty-intermediate = 中級
+difficulty-expert = 上級
+difficulty-custom = カスタム
+tile-size-adaptive = 自動
+pixels = { $value } px
+chord-mode-uncover = 開く操作
+chord-mode-chord-button = 専用ボタンのみ
+chord-mode-disabled = オフ
+number-palette-theme = テーマ
+number-palette-deuteranopia = 2型色覚
+number-palette-protanopia = 1型色覚
+number-palette-tritanopia = 3型色覚
+number-palette-monochrome = モノクロ
+number-decoration-dice = サイコロ
+number-decoration-glyphs = 記号
+
+## Gamepad and touch screen hints
+
+gamepad-uncover = 開く
+gamepad-flag = 旗
+gamepad-chord = 同時開き
+gamepad-move = 移動
+gamepad-pan = スクロール
+gamepad-zoom = ズーム
+gamepad-menu = メニュー
+touch-mode-uncover = タップ: 開く
+touch-mode-flag = タップ: 旗
+
+## Screen reader
+
+a11y-board = { $width } x { $height } マスの盤面、地雷 { $mines } 個
+a11y-tile = { $column } 列 { $row } 行、{ $state ->
+        [flagged] 旗
+        [question-mark] ?マーク
+        [mine] 地雷
+        [empty] 空白
+        [number] 周囲の地雷 { $count } 個
+       *[covered] 未開封
+    }
+a11y-cascade = { $count } マスが開きました
+a11y-explosion = { $column } 列 { $row } 行の地雷が爆発しました。ゲームオーバー
+a11y-victory = { $time } で盤面をクリアしました
+a11y-summary = 未開封 { $covered } マス、旗 { $flags } 本 (地雷 { $mines } 個)、時間 { $time }
@@ -0,0 +1,224 @@
+# Polish texts, missing messages are shown in English.
+
+language-name = Polski
+game-title = Rust Minesweeper
+
+## Shared
+
+hint-back = Naciśnij { $key }, aby wrócić
+percent = { $value }%
+on = Wł.
+off = Wył.
+
+## Main menu and pause menu
+
+menu-new-game = Nowa gra
+menu-continue = Kontynuuj
+menu-daily = Wyzwanie dnia
+menu-puzzles = Łamigłówki
+menu-statistics = Statystyki
+menu-settings = Ustawienia
+menu-main-menu = Menu główne
+menu-quit = Wyjdź
+pause-title = Pauza
+pause-subtitle = Czas: { $time } | Naciśnij { $key }, aby kontynuować
+
+## Statistics
+
+statistics-title = Statystyki
+statistics-played = Rozegrane gry
+statistics-won = Wygrane gry
+statistics-win-rate = Odsetek wygranych
+statistics-current-streak = Obecna seria
+statistics-best-streak = Najlepsza seria
+statistics-best-times = Najlepsze czasy
+statistics-no-wins = Nie wygrano jeszcze żadnej planszy
+statistics-board = { $width }x{ $height }, { $mines ->
+    [one] { $mines } mina
+    [few] { $mines } miny
+    [many] { $mines } min
+   *[other] { $mines } miny
+}
+
+## Puzzles
+
+puzzles-title = Łamigłówki
+puzzles-none = Nie znaleziono łamigłówek
+puzzles-solved = Rozwiązana
+puzzle-mines-left = Pozostałe miny: { $count }
+
+## Daily challenge
+
+daily-title = Wyzwanie dnia - { $month } { $year }
+daily-lost = Przegrana
+daily-done = Dzisiejsze wyzwanie zakończone. Wróć jutro!
+daily-play = Naciśnij { $key }, aby zagrać w dzisiejsze wyzwanie
+daily-hint = { $previous } / { $next }: zmiana miesiąca | { $back }: powrót
+month-1 = styczeń
+month-2 = luty
+month-3 = marzec
+month-4 = kwiecień
+month-5 = maj
+month-6 = czerwiec
+month-7 = lipiec
+month-8 = sierpień
+month-9 = wrzesień
+month-10 = październik
+month-11 = listopad
+month-12 = grudzień
+weekday-1 = Pn
+weekday-2 = Wt
+weekday-3 = Śr
+weekday-4 = Cz
+weekday-5 = Pt
+weekday-6 = So
+weekday-7 = Nd
+
+## Board editor
+
+editor-help = { $mine }: mina | { $cover }: odkryj / flaga | { $save }: zapisz | { $clear }: wyczyść | { $back }: powrót
+editor-status = Miny: { $mines } | Odkryte: { $revealed } | Rozwiązywalna: { $solvable ->
+        [yes] tak
+       *[no] nie
+    }
+
+## Controls
+
+controls-title = Sterowanie
+controls-reset = Przywróć domyślne
+controls-capture =
+    Naciśnij klawisz lub przycisk myszy dla '{ $action }'
+    Escape anuluje, { $key } usuwa wszystkie przypisania
+controls-conflict = { $binding } jest przypisany do '{ $first }' i '{ $second }'
+controls-hint = Kliknij akcję, aby dodać przypisanie. Naciśnij { $key }, aby wrócić
+action-uncover = Odkryj
+action-flag = Flaga
+action-chord = Akord
+action-cursor-left = Kursor w lewo
+action-cursor-right = Kursor w prawo
+action-cursor-up = Kursor w górę
+action-cursor-down = Kursor w dół
+action-next-unresolved = Następne nierozwiązane pole
+action-pause = Pauza
+action-clear-board = Wyczyść planszę
+action-export-board = Eksportuj planszę
+action-describe-board = Opisz planszę
+action-restart = Nowa plansza
+action-open-puzzles = Łamigłówki
+action-open-editor = Edytor planszy
+action-open-daily = Wyzwanie dnia
+action-open-controls = Sterowanie
+action-back = Powrót
+action-previous-month = Poprzedni miesiąc
+action-next-month = Następny miesiąc
+action-start-daily = Zagraj w dzisiejsze wyzwanie
+action-toggle-mine = Edytor: przełącz minę
+action-toggle-cover = Edytor: odkryj / flaga
+action-save-level = Edytor: zapisz
+action-clear-level = Edytor: wyczyść
+action-clear-bindings = Usuń przypisania
+action-pan-camera = Przesuwanie (przytrzymaj)
+action-zoom-in = Przybliż
+action-zoom-out = Oddal
+action-zoom-to-fit = Dopasuj widok
+action-toggle-vsync = Przełącz VSync
+action-toggle-fullscreen = Przełącz pełny ekran
+action-next-monitor = Następny monitor
+action-next-theme = Następny motyw
+action-toggle-animations = Przełącz animacje
+action-toggle-mute = Przełącz wyciszenie
+
+## Settings
+
+settings-title = Ustawienia
+settings-board = Plansza
+settings-display = Obraz
+settings-gameplay = Rozgrywka
+settings-audio = Dźwięk
+settings-controls = Sterowanie
+settings-hint = Rozmiar planszy i liczba min obowiązują od następnej gry. Shift-klik zmienia szybciej
+setting-difficulty = Poziom trudności
+setting-width = Szerokość
+setting-height = Wysokość
+setting-mines = Miny
+setting-safe-start = Bezpieczny start
+setting-tile-padding = Odstęp pól
+setting-tile-size = Rozmiar pól
+setting-vsync = VSync
+setting-fullscreen = Pełny ekran
+setting-theme = Motyw
+setting-language = Język
+setting-number-colors = Kolory liczb
+setting-number-shapes = Kształty liczb
+setting-question-marks = Znaki zapytania
+setting-chording = Akordy
+setting-animations = Animacje
+setting-master-volume = Głośność ogólna
+setting-effects-volume = Głośność efektów
+setting-music-volume = Głośność muzyki
+setting-mute = Wyciszenie
+difficulty-beginner = Początkujący
+difficulty-intermediate = Średni
+difficulty-expert = Ekspert
+difficulty-custom = Własny
+tile-size-adaptive = Dopasowany
+pixels = { $value } px
+chord-mode-uncover = Odkrywanie
+chord-mode-chord-button = Tylko przycisk
+chord-mode-disabled = Wył.
+number-palette-theme = Motyw
+number-palette-deuteranopia = Deuteranopia
+number-palette-protanopia = Protanopia
+number-palette-tritanopia = Tritanopia
+number-palette-monochrome = Monochromatyczne
+number-decoration-dice = Kości
+number-decoration-glyphs = Symbole
+
+## Gamepad and touch screen hints
+
+gamepad-uncover = Odkryj
+gamepad-flag = Flaga
+gamepad-chord = Akord
+gamepad-move = Ruch
+gamepad-pan = Przesuń
+gamepad-zoom = Zoom
+gamepad-menu = Menu
+touch-mode-uncover = Dotyk: odkryj
+touch-mode-flag = Dotyk: flaga
+
+## Screen reader
+
+a11y-board = Plansza { $width } na { $height } pól, { $mines ->
+        [one] { $mines } mina
+        [few] { $mines } miny
+        [many] { $mines } min
+       *[other] { $mines } miny
+    }
+a11y-tile = Kolumna { $column }, wiersz { $row }, { $state ->
+        [flagged] flaga
+        [question-mark] znak zapytania
+        [mine] mina
+        [empty] puste
+        [number] { $count ->
+            [one] { $count } mina obok
+            [few] { $count } miny obok
+           *[many] { $count } min obok
+        }
+       *[covered] zakryte
+    }
+a11y-cascade = { $count ->
+        [one] Odkryto { $count } pole
+        [few] Odkryto { $count } pola
+       *[many] Odkryto { $count } pól
+    }
+a11y-explosion = Mina wybuchła w kolumnie { $column }, wierszu { $row }. Koniec gry
+a11y-victory = Plansza oczyszczona w czasie { $time }
+a11y-summary = { $covered ->
+        [one] { $covered } zakryte pole
+        [few] { $covered } zakryte pola
+       *[many] { $covered } zakrytych pól
+    }, { $flags ->
+        [one] { $flags } flaga
+        [few] { $flags } flagi
+       *[many] { $flags } flag
+    } na { $mines } min, czas { $time }
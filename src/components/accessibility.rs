@@ -0,0 +1,18 @@
+use bevy::prelude::Component;
+
+/// Accessibility node describing the board as a whole.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct AccessibleBoard;
+
+/// Accessibility node describing the tile under the keyboard cursor, or under the mouse. It
+/// holds the focus during a game.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct AccessibleTile;
+
+/// Accessibility live region, screen readers read out every new text of it.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct Announcer;
+
+/// Decoration drawn on a number, see `AccessibilitySettings::number_decoration`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct NumberShape;
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// The looping music track, keeping the handle it was spawned with so it can be replaced when
+/// the theme changes.
+#[derive(Debug, Clone, Component)]
+pub struct BackgroundMusic(pub Handle<AudioSource>);
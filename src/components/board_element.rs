@@ -0,0 +1,21 @@
+use bevy::prelude::Component;
+#[cfg(feature = "debug")]
+use bevy::prelude::Reflect;
+#[cfg(feature = "debug")]
+use bevy_inspector_egui::prelude::*;
+
+/// Identifies which `BoardAssets` material a board entity is drawn with, so the board can be
+/// re-skinned in place when the assets change.
+#[cfg_attr(feature = "debug", derive(Reflect, InspectorOptions))]
+#[cfg_attr(feature = "debug", reflect(InspectorOptions))]
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Component)]
+pub enum BoardElement {
+    #[default]
+    Background,
+    Tile,
+    Cover,
+    Flag,
+    QuestionMark,
+    Mine,
+    MineCounter(u8),
+}
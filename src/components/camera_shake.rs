@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+/// Shakes the camera it is attached to. The offset applied last frame is tracked so the shake
+/// does not interfere with other systems moving the camera.
+#[derive(Debug, Copy, Clone, Component)]
+pub struct CameraShake {
+    pub intensity: f32,
+    pub duration: f32,
+    pub remaining: f32,
+    pub offset: Vec2,
+}
+
+impl CameraShake {
+    pub fn new(intensity: f32, duration: f32) -> Self {
+        Self {
+            intensity,
+            duration,
+            remaining: duration,
+            offset: Vec2::ZERO,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::Component;
+
+use crate::resources::Action;
+
+/// Root node of the controls screen.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct ControlsScreen;
+
+/// Button waiting for a new binding of the action when pressed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub struct RebindButton(pub Action);
+
+/// Text listing the current bindings of the action.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub struct BindingText(pub Action);
+
+/// Button restoring the default bindings.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct ResetBindingsButton;
+
+/// Text showing conflicts and what the screen is waiting for.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct ControlsStatusText;
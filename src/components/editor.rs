@@ -0,0 +1,9 @@
+use bevy::prelude::Component;
+
+/// Root node of the editor overlay.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct EditorScreen;
+
+/// Text showing the editor board statistics and solvability.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct EditorStatusText;
@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+/// Root node of the controller button hints, shown while a gamepad is connected.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct GamepadHud;
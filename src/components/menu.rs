@@ -0,0 +1,20 @@
+use bevy::prelude::Component;
+
+/// Root node of the main menu and of the pause menu.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct MenuScreen;
+
+/// Entry of the main menu or of the pause menu.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub enum MenuButton {
+    NewGame,
+    /// Resumes the paused game.
+    Continue,
+    Daily,
+    Puzzles,
+    Statistics,
+    Settings,
+    /// Leaves the pause menu, keeping the game so it can be continued.
+    MainMenu,
+    Quit,
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+/// Text showing the number of mines left to flag while playing a puzzle.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct MineCounter;
@@ -1,9 +1,64 @@
+pub use accessibility::AccessibleBoard;
+pub use accessibility::AccessibleTile;
+pub use accessibility::Announcer;
+pub use accessibility::NumberShape;
+pub use background_music::BackgroundMusic;
+pub use board_element::BoardElement;
+pub use camera_shake::CameraShake;
+pub use controls_screen::BindingText;
+pub use controls_screen::ControlsScreen;
+pub use controls_screen::ControlsStatusText;
+pub use controls_screen::RebindButton;
+pub use controls_screen::ResetBindingsButton;
 pub use coordinates::Coordinates;
+pub use daily_screen::DailyScreen;
+pub use editor::EditorScreen;
+pub use editor::EditorStatusText;
+pub use gamepad_hud::GamepadHud;
+pub use menu::MenuButton;
+pub use menu::MenuScreen;
 pub use mine::Mine;
+pub use mine_counter::MineCounter;
 pub use mine_neighbor::MineNeighbor;
+pub use particle::Particle;
+pub use puzzle_select::PuzzleButton;
+pub use puzzle_select::PuzzleSelectScreen;
+pub use settings_screen::NumberPreview;
+pub use settings_screen::OpenControlsButton;
+pub use settings_screen::Setting;
+pub use settings_screen::SettingButton;
+pub use settings_screen::SettingValueText;
+pub use settings_screen::SettingsScreen;
+pub use statistics_screen::StatisticsScreen;
+pub use tile_animation::TileAnimation;
+pub use tile_animation::TileAnimationKind;
+pub use tile_cursor_highlight::TileCursorHighlight;
+pub use tile_hover_highlight::TileHoverHighlight;
+pub use touch::LongPressRing;
+pub use touch::LongPressRingSegment;
+pub use touch::TouchModeButton;
+pub use touch::TouchModeText;
 pub use uncover::Uncover;
 
+mod accessibility;
+mod background_music;
+mod board_element;
+mod camera_shake;
+mod controls_screen;
 mod coordinates;
+mod daily_screen;
+mod editor;
+mod gamepad_hud;
+mod menu;
 mod mine;
+mod mine_counter;
 mod mine_neighbor;
+mod particle;
+mod puzzle_select;
+mod settings_screen;
+mod statistics_screen;
+mod tile_animation;
+mod tile_cursor_highlight;
+mod tile_hover_highlight;
+mod touch;
 mod uncover;
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+/// A short-lived sprite moved by the CPU particle system. Velocities are in pixels per second.
+#[derive(Debug, Copy, Clone, Component)]
+pub struct Particle {
+    pub velocity: Vec2,
+    /// Downwards acceleration, negative values make the particle rise.
+    pub gravity: f32,
+    /// Fraction of the velocity lost per second.
+    pub drag: f32,
+    /// Rotation speed in radians per second.
+    pub spin: f32,
+    /// Scale reached at the end of the lifetime, relative to the spawn size.
+    pub end_scale: f32,
+    pub lifetime: f32,
+    pub age: f32,
+    pub alpha: f32,
+}
+
+impl Particle {
+    pub fn new(velocity: Vec2, lifetime: f32) -> Self {
+        Self {
+            velocity,
+            gravity: 0f32,
+            drag: 0f32,
+            spin: 0f32,
+            end_scale: 1f32,
+            lifetime,
+            age: 0f32,
+            alpha: 1f32,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::Component;
+
+/// Root node of the puzzle selection screen.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct PuzzleSelectScreen;
+
+/// Button selecting the puzzle with the given index in the `PuzzlePack`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub struct PuzzleButton(pub usize);
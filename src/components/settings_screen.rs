@@ -0,0 +1,50 @@
+use bevy::prelude::Component;
+
+/// Root node of the settings screen.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct SettingsScreen;
+
+/// Option shown on a row of the settings screen.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Setting {
+    /// Board size and mine count presets.
+    Difficulty,
+    Width,
+    Height,
+    Mines,
+    SafeStart,
+    TilePadding,
+    TileSize,
+    Vsync,
+    Fullscreen,
+    Theme,
+    Language,
+    NumberColors,
+    NumberShapes,
+    QuestionMarks,
+    Chording,
+    Animations,
+    MasterVolume,
+    EffectsVolume,
+    MusicVolume,
+    Mute,
+}
+
+/// Arrow button changing a setting to its previous or next value.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub struct SettingButton {
+    pub setting: Setting,
+    pub forward: bool,
+}
+
+/// Text showing the current value of a setting.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub struct SettingValueText(pub Setting);
+
+/// Row of sample numbers on the settings screen, showing the number colours and decorations.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct NumberPreview;
+
+/// Button opening the controls screen.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct OpenControlsButton;
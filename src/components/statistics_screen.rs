@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+/// Root node of the statistics screen.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct StatisticsScreen;
@@ -0,0 +1,46 @@
+use bevy::prelude::Component;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TileAnimationKind {
+    /// A copy of an uncovered tile cover fading out, despawned once finished.
+    Fade,
+    /// A copy of an uncovered tile cover flipping over, despawned once finished.
+    Flip,
+    /// A flag dropping onto its tile.
+    Drop,
+    /// A mine cover fading out after an explosion, hidden once finished.
+    Reveal,
+}
+
+/// A running animation of a board sprite. Times are in seconds and already scaled by the
+/// animation speed.
+#[derive(Debug, Copy, Clone, Component)]
+pub struct TileAnimation {
+    pub kind: TileAnimationKind,
+    pub delay: f32,
+    pub duration: f32,
+    pub elapsed: f32,
+}
+
+impl TileAnimation {
+    pub fn new(kind: TileAnimationKind, delay: f32, duration: f32) -> Self {
+        Self {
+            kind,
+            delay,
+            duration,
+            elapsed: 0f32,
+        }
+    }
+
+    /// Progress of the animation between `0` and `1`, staying at `0` during the delay.
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0f32 {
+            return 1f32;
+        }
+        ((self.elapsed - self.delay) / self.duration).clamp(0f32, 1f32)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.delay + self.duration
+    }
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+/// Sprite drawn around the tile selected by the keyboard cursor.
+#[derive(Debug, Default, Copy, Clone, Component)]
+pub struct TileCursorHighlight;
@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+/// Sprite drawn over the tile under the mouse.
+#[derive(Debug, Default, Copy, Clone, Component)]
+pub struct TileHoverHighlight;
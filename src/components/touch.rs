@@ -0,0 +1,17 @@
+use bevy::prelude::Component;
+
+/// Ring around a tile filling up while it is long pressed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct LongPressRing;
+
+/// Segment of the long press ring, shown once the press reaches its share of the ring.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Component)]
+pub struct LongPressRingSegment(pub usize);
+
+/// Button switching taps between uncovering and flagging.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct TouchModeButton;
+
+/// Text of the touch mode button.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Component)]
+pub struct TouchModeText;
@@ -1,4 +1,5 @@
 use std::io::Cursor;
+use std::path::Path;
 #[cfg(feature = "debug")]
 use std::time::Duration;
 
@@ -7,56 +8,78 @@ use bevy::diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
 #[cfg(feature = "debug")]
 use bevy::prelude::Reflect;
 use bevy::prelude::*;
-use bevy::window::{PresentMode, PrimaryWindow, WindowTheme};
+use bevy::window::PrimaryWindow;
 use bevy::winit::WinitWindows;
 #[cfg(feature = "debug")]
 use bevy_inspector_egui::prelude::*;
 #[cfg(feature = "debug")]
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
-use resources::{BoardAssets, SpriteMaterial};
 use winit::window::Icon;
 
 #[cfg(feature = "debug")]
 use crate::components::{Coordinates, Mine, MineNeighbor, Uncover};
-use crate::plugins::BoardPlugin;
-use crate::resources::BoardOptions;
+use crate::plugins::{
+    AccessibilityPlugin, AnimationPlugin, BoardPlugin, CameraPlugin, ControlsPlugin, CursorPlugin,
+    DailyPlugin, EditorPlugin, EffectsPlugin, GamepadPlugin, InputSet, LocalizationPlugin,
+    MenuPlugin, PointerPlugin, PuzzlePlugin, SettingsPlugin, SoundPlugin, StatisticsPlugin,
+    ThemePlugin, TouchPlugin, WindowSettingsPlugin,
+};
+use crate::resources::{
+    Action, AnimationSettings, Board, BoardFormat, BoardLayout, BoardMask, BoardOptions, InputMap,
+    WindowSettings,
+};
 use crate::systems::{make_window_visible_after_startup, setup_2d_camera, toggle_vsync};
 
 mod components;
+mod persistence;
 mod plugins;
 mod resources;
 mod systems;
 
 const WINDOW_TITLE: &str = "Rust Minesweeper";
-const INITIAL_RESOLUTION_X: u16 = 800;
-const INITIAL_RESOLUTION_Y: u16 = 800;
+const MASK_ARG: &str = "--mask";
+const BOARD_ARG: &str = "--board";
+const ANIMATION_SPEED_ARG: &str = "--animation-speed";
+const NO_ANIMATIONS_ARG: &str = "--no-animations";
 
 #[cfg_attr(feature = "debug", derive(Reflect, InspectorOptions))]
 #[cfg_attr(feature = "debug", reflect(InspectorOptions))]
 #[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, States)]
 pub enum AppState {
     #[default]
+    MainMenu,
     InGame,
-    Out,
+    Paused,
+    Statistics,
+    PuzzleSelect,
+    Editor,
+    Daily,
+    Controls,
+    Settings,
 }
 
 fn main() {
     let mut app = App::new();
 
+    let window_settings = WindowSettings::load();
+    app.insert_resource(window_settings);
+
     // Set window properties
     app.add_plugins(DefaultPlugins.set(WindowPlugin {
         primary_window: Some(Window {
             title: WINDOW_TITLE.into(),
-            resolution: (INITIAL_RESOLUTION_X, INITIAL_RESOLUTION_Y).into(),
-            present_mode: PresentMode::AutoVsync,
-            window_theme: Some(WindowTheme::Dark),
+            resolution: window_settings.size.into(),
+            present_mode: window_settings.present_mode(),
+            // Let the OS decide, the board theme follows it
+            window_theme: None,
             focused: true,
             enabled_buttons: bevy::window::EnabledButtons {
                 maximize: true,
                 close: true,
                 minimize: true,
             },
-            position: WindowPosition::Centered(MonitorSelection::Primary),
+            // Fullscreen is entered by the WindowSettingsPlugin once the window is on its monitor
+            position: window_settings.window_position(),
             // This will spawn an invisible window
             // The window will be made visible in the make_visible() system after 3 frames.
             // This is useful when you want to avoid the white window that shows up before the GPU is ready to render the app.
@@ -73,16 +96,43 @@ fn main() {
     register_custom_types_for_bevy_inspector_egui(&mut app);
 
     app.add_state::<AppState>();
+    app.add_plugins(LocalizationPlugin);
+    app.add_plugins(ControlsPlugin);
+    app.add_plugins(WindowSettingsPlugin);
+    app.add_plugins(ThemePlugin);
     app.add_plugins(BoardPlugin {
         running_state: AppState::InGame,
+        // Screens opened from the main menu keep the game, it can be continued from there
+        paused_states: vec![
+            AppState::Paused,
+            AppState::MainMenu,
+            AppState::Statistics,
+            AppState::Controls,
+            AppState::Settings,
+        ],
     });
+    app.add_plugins(CameraPlugin);
+    app.add_plugins(CursorPlugin);
+    app.add_plugins(PointerPlugin);
+    app.add_plugins(GamepadPlugin);
+    app.add_plugins(TouchPlugin);
+    app.add_plugins(AnimationPlugin);
+    app.add_plugins(EffectsPlugin);
+    app.add_plugins(SoundPlugin);
+    app.add_plugins(PuzzlePlugin);
+    app.add_plugins(EditorPlugin);
+    app.add_plugins(DailyPlugin);
+    app.add_plugins(MenuPlugin);
+    app.add_plugins(StatisticsPlugin);
+    app.add_plugins(AccessibilityPlugin);
+    app.add_plugins(SettingsPlugin);
 
     app.add_systems(Startup, (set_window_icon, setup_2d_camera, setup_board));
     app.add_systems(
         Update,
         (
             make_window_visible_after_startup,
-            toggle_vsync,
+            toggle_vsync.in_set(InputSet::Shortcuts),
             state_handler,
         ),
     );
@@ -133,70 +183,167 @@ fn register_custom_types_for_bevy_inspector_egui(app: &mut App) {
 }
 
 fn state_handler(
+    mut commands: Commands,
+    board: Option<Res<Board>>,
     current_state: Res<State<AppState>>,
     mut next_state: ResMut<NextState<AppState>>,
+    input_map: Res<InputMap>,
     keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<MouseButton>>,
 ) {
-    if keys.just_pressed(KeyCode::C) {
-        info!("[C] key pressed. Attempting to clear the board");
+    let just_pressed = |action| input_map.just_pressed(action, &keys, &buttons);
+
+    if just_pressed(Action::ClearBoard) {
+        info!(
+            "[{}] pressed. Attempting to clear the board",
+            input_map.label(Action::ClearBoard)
+        );
         if current_state.get() == &AppState::InGame {
             info!("Clearing the board");
-            next_state.set(AppState::Out);
+            if let Some(board) = &board {
+                BoardPlugin::<AppState>::discard_board(&mut commands, board);
+            }
+            next_state.set(AppState::MainMenu);
         } else {
-            warn!("Wrong state detected. Game was already cleared before. Press 'R' to regenerate the board")
+            warn!(
+                "Wrong state detected. Game was already cleared before. Press '{}' to regenerate the board",
+                input_map.label(Action::Restart)
+            )
         }
     }
 
-    if keys.just_pressed(KeyCode::R) {
-        info!("[R] key pressed. Attempting to regenerate the board");
-        if current_state.get() == &AppState::Out {
-            info!("Regenerating the game board");
-            next_state.set(AppState::InGame);
-        } else {
-            warn!("Wrong state detected. Clear the board with 'C' before regenerating the board")
+    let menu_actions = [
+        (Action::Restart, AppState::InGame, "regenerate the board"),
+        (Action::OpenPuzzles, AppState::PuzzleSelect, "open puzzles"),
+        (Action::OpenEditor, AppState::Editor, "open the editor"),
+        (
+            Action::OpenDaily,
+            AppState::Daily,
+            "open the daily challenge",
+        ),
+        (
+            Action::OpenControls,
+            AppState::Controls,
+            "open the controls",
+        ),
+    ];
+    for (action, state, description) in menu_actions {
+        if !just_pressed(action) {
+            continue;
+        }
+
+        if current_state.get() == &AppState::MainMenu {
+            info!(
+                "[{}] pressed. Attempting to {}",
+                input_map.label(action),
+                description
+            );
+            // A paused game would be continued instead of regenerated
+            if let Some(board) = board.as_ref().filter(|_| state == AppState::InGame) {
+                BoardPlugin::<AppState>::discard_board(&mut commands, board);
+            }
+            next_state.set(state);
+        } else if current_state.get() != &AppState::InGame {
+            // In game the same keys may be bound to game actions, like moving the cursor
+            warn!(
+                "Wrong state detected. Go back to the main menu before trying to {}",
+                description
+            )
         }
     }
 }
 
-fn setup_board(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-) {
+fn setup_board(mut commands: Commands, mut next_state: ResMut<NextState<AppState>>) {
     info!("Setting up the board");
 
-    commands.insert_resource(BoardOptions {
-        map_size: (20, 20),
-        mine_count: 60,
-        tile_padding: 3.0,
-        safe_start_enabled: true,
-        ..default()
-    });
+    let board_options = BoardOptions::load();
 
-    commands.insert_resource(BoardAssets {
-        label: "Default".into(),
-        board_material: SpriteMaterial {
-            color: Color::BLACK,
-            ..default()
-        },
-        tile_material: SpriteMaterial {
-            color: Color::DARK_GRAY,
-            ..default()
-        },
-        covered_tile_material: SpriteMaterial {
-            color: Color::GRAY,
-            ..default()
-        },
-        mine_counter_colors: BoardAssets::default_colors(),
-        mine_counter_font: asset_server.load("fonts/symtext/Symtext.ttf"),
-        flag_material: SpriteMaterial {
-            color: Color::WHITE,
-            texture: asset_server.load("sprites/Flag.png"),
-        },
-        mine_material: SpriteMaterial {
-            color: Color::WHITE,
-            texture: asset_server.load("sprites/Mine.png"),
-        },
-    });
+    if let Some(layout) = board_layout_from_args() {
+        commands.insert_resource(layout);
+        // An imported board is played right away instead of going through the main menu
+        next_state.set(AppState::InGame);
+    } else if let Some(mask) = board_mask_from_args(board_options.map_size.0) {
+        commands.insert_resource(mask);
+    }
+
+    commands.insert_resource(board_options);
+    commands.insert_resource(animation_settings_from_args());
 
     info!("Board has been configured");
 }
+
+/// Reads the board shape passed with `--mask <circle|heart|path>`.
+/// Paths can point to a `.png` image or to a text file.
+fn board_mask_from_args(size: u16) -> Option<BoardMask> {
+    let value = arg_value(MASK_ARG)?;
+
+    let mask = match value.as_str() {
+        "circle" => Ok(BoardMask::circle(size)),
+        "heart" => Ok(BoardMask::heart(size)),
+        path => BoardMask::load(Path::new(path)),
+    };
+
+    match mask {
+        Ok(mask) => {
+            info!(
+                "Using board mask '{}' ({}x{}, {} tiles)",
+                value,
+                mask.width(),
+                mask.height(),
+                mask.tile_count()
+            );
+            Some(mask)
+        }
+        Err(e) => {
+            error!("Failed to load board mask '{}': {}", value, e);
+            None
+        }
+    }
+}
+
+/// Reads a board layout passed with `--board <path>`. Supported formats are plain text grids
+/// (`.txt`, `.board`) and Minesweeper X boards (`.mbf`).
+fn board_layout_from_args() -> Option<BoardLayout> {
+    let path = arg_value(BOARD_ARG)?;
+
+    match BoardFormat::load(Path::new(&path)) {
+        Ok(tile_map) => {
+            info!(
+                "Using board layout from '{}' ({}x{}, {} mines)",
+                path,
+                tile_map.width(),
+                tile_map.height(),
+                tile_map.mine_count()
+            );
+            Some(BoardLayout::new(tile_map))
+        }
+        Err(e) => {
+            error!("Failed to import board layout '{}': {}", path, e);
+            None
+        }
+    }
+}
+
+/// Reads `--animation-speed <multiplier>` and `--no-animations`, overriding the saved settings.
+fn animation_settings_from_args() -> AnimationSettings {
+    let mut settings = AnimationSettings::load();
+    if std::env::args().any(|arg| arg == NO_ANIMATIONS_ARG) {
+        settings.disabled_by_args = true;
+    }
+
+    if let Some(value) = arg_value(ANIMATION_SPEED_ARG) {
+        match value.parse::<f32>() {
+            Ok(speed) if speed > 0f32 => settings.speed_from_args = Some(speed),
+            _ => error!(
+                "Invalid animation speed '{}', using {}",
+                value, settings.speed
+            ),
+        }
+    }
+
+    settings
+}
+
+fn arg_value(name: &str) -> Option<String> {
+    std::env::args().skip_while(|arg| arg != name).nth(1)
+}
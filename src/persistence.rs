@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use bevy::asset::io::file::FileAssetReader;
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+const APP_DIRECTORY: &str = "rust-minesweeper";
+
+/// Directory used for save files. Falls back to the working directory when the platform data
+/// directory cannot be determined.
+pub fn data_directory() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(APP_DIRECTORY)
+}
+
+/// Directory Bevy loads assets from, for content read directly from disk.
+pub fn assets_directory() -> PathBuf {
+    FileAssetReader::get_base_path().join("assets")
+}
+
+/// Loads a RON file from the data directory. Missing or malformed files are reported and
+/// treated as absent.
+pub fn load<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+    let path = data_directory().join(file_name);
+    let content = std::fs::read_to_string(&path).ok()?;
+
+    match ron::from_str(&content) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            warn!("Failed to parse {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Saves a value as a RON file in the data directory.
+pub fn save<T: Serialize>(file_name: &str, value: &T) {
+    let directory = data_directory();
+    let path = directory.join(file_name);
+
+    let content = match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(content) => content,
+        Err(e) => {
+            error!("Failed to serialize {}: {}", file_name, e);
+            return;
+        }
+    };
+
+    if let Err(e) = std::fs::create_dir_all(&directory).and_then(|_| std::fs::write(&path, content))
+    {
+        error!("Failed to save {}: {}", path.display(), e);
+    }
+}
@@ -0,0 +1,434 @@
+use std::time::Duration;
+
+use bevy::a11y::accesskit::{Live, NodeBuilder, Role};
+use bevy::a11y::{AccessibilityNode, Focus};
+use bevy::prelude::*;
+use bevy::sprite::{MaterialMesh2dBundle, Mesh2dHandle};
+
+use crate::components::{
+    AccessibleBoard, AccessibleTile, Announcer, Coordinates, MineNeighbor, NumberShape, Uncover,
+};
+use crate::plugins::{BoardCompletedEvent, MineExplosionEvent, TileTriggerEvent};
+use crate::resources::{
+    AccessibilitySettings, Action, Board, BoardAssets, GameTimer, InputMap, Localization,
+    NumberDecoration, TileCursor, TilePointer,
+};
+use crate::systems::uncover_tiles;
+use crate::AppState;
+
+/// Highest number of mines around a tile.
+const MAX_COUNT: u8 = 8;
+/// Center of the decoration, in the bottom right corner of the tile. Distances are in tiles.
+const PATTERN_CENTER: Vec2 = Vec2::new(0.3, -0.3);
+const PIP_SPACING: f32 = 0.09;
+const PIP_RADIUS: f32 = 0.035;
+const GLYPH_SIZE: f32 = 0.3;
+/// Above the number, below the cover.
+const PATTERN_Z: f32 = 1.5;
+
+/// Pip mesh and one material per number, coloured like the numbers.
+#[derive(Debug, Resource)]
+struct NumberShapeAssets {
+    mesh: Mesh2dHandle,
+    materials: Vec<Handle<ColorMaterial>>,
+}
+
+impl FromWorld for NumberShapeAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(shape::Circle::new(PIP_RADIUS).into());
+        let mut materials = world.resource_mut::<Assets<ColorMaterial>>();
+        Self {
+            mesh: mesh.into(),
+            materials: (0..MAX_COUNT)
+                .map(|_| materials.add(ColorMaterial::from(Color::WHITE)))
+                .collect(),
+        }
+    }
+}
+
+/// Screen reader support through AccessKit: the focused tile and the board are described, and
+/// cascades, explosions and victories are announced. Also draws the optional decorations on the
+/// numbers, see `AccessibilitySettings`.
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(AccessibilitySettings::load());
+        app.init_resource::<NumberShapeAssets>();
+
+        app.add_systems(Startup, Self::spawn_accessibility_nodes);
+        app.add_systems(OnEnter(AppState::InGame), Self::focus_tile);
+        app.add_systems(OnExit(AppState::InGame), Self::release_focus);
+        app.add_systems(
+            Update,
+            (
+                Self::describe_board,
+                Self::describe_focused_tile,
+                Self::announce_cascades.after(uncover_tiles),
+                Self::announce_results.after(uncover_tiles),
+                Self::announce_summary,
+            )
+                .run_if(in_state(AppState::InGame)),
+        );
+        app.add_systems(
+            Update,
+            (Self::color_number_shapes, Self::update_number_shapes),
+        );
+
+        info!("Loaded Accessibility Plugin");
+    }
+}
+
+impl AccessibilityPlugin {
+    fn spawn_accessibility_nodes(mut commands: Commands) {
+        commands
+            .spawn((
+                Name::new("Accessible Board"),
+                AccessibleBoard,
+                AccessibilityNode(NodeBuilder::new(Role::Grid)),
+            ))
+            .with_children(|parent| {
+                let mut node = NodeBuilder::new(Role::Cell);
+                node.add_action(bevy::a11y::accesskit::Action::Focus);
+                parent.spawn((
+                    Name::new("Accessible Tile"),
+                    AccessibleTile,
+                    AccessibilityNode(node),
+                ));
+            });
+
+        let mut node = NodeBuilder::new(Role::Status);
+        node.set_live(Live::Assertive);
+        commands.spawn((Name::new("Announcer"), Announcer, AccessibilityNode(node)));
+    }
+
+    fn focus_tile(mut focus: ResMut<Focus>, tiles: Query<Entity, With<AccessibleTile>>) {
+        if let Ok(entity) = tiles.get_single() {
+            focus.0 = Some(entity);
+        }
+    }
+
+    fn release_focus(mut focus: ResMut<Focus>, tiles: Query<Entity, With<AccessibleTile>>) {
+        if focus.0.is_some_and(|entity| tiles.contains(entity)) {
+            focus.0 = None;
+        }
+    }
+
+    fn describe_board(
+        board: Option<Res<Board>>,
+        localization: Res<Localization>,
+        mut nodes: Query<&mut AccessibilityNode, With<AccessibleBoard>>,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        if !board.is_changed() && !localization.is_changed() {
+            return;
+        }
+
+        let tile_map = &board.tile_map;
+        let description = localization.text_with(
+            "a11y-board",
+            &[
+                ("width", tile_map.width().into()),
+                ("height", tile_map.height().into()),
+                ("mines", tile_map.mine_count().into()),
+            ],
+        );
+        for mut node in nodes.iter_mut() {
+            node.set_name(description.clone());
+        }
+    }
+
+    /// Describes the tile under the keyboard cursor while it is shown, otherwise the tile under
+    /// the mouse.
+    fn describe_focused_tile(
+        board: Option<Res<Board>>,
+        cursor: Res<TileCursor>,
+        pointer: Res<TilePointer>,
+        localization: Res<Localization>,
+        mut nodes: Query<&mut AccessibilityNode, With<AccessibleTile>>,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        if !board.is_changed()
+            && !cursor.is_changed()
+            && !pointer.is_changed()
+            && !localization.is_changed()
+        {
+            return;
+        }
+
+        let focused = if cursor.visible {
+            Some(cursor.coordinates)
+        } else {
+            pointer.hovered
+        };
+        let description = focused.map_or_else(String::new, |coordinates| {
+            Self::describe_tile(&board, &localization, coordinates)
+        });
+        for mut node in nodes.iter_mut() {
+            if node.name() != Some(description.as_str()) {
+                node.set_name(description.clone());
+            }
+        }
+    }
+
+    /// Announces how many tiles were uncovered once a trigger uncovering more than one tile
+    /// is done spreading.
+    fn announce_cascades(
+        board: Res<Board>,
+        localization: Res<Localization>,
+        uncovering: Query<(), With<Uncover>>,
+        mut announcers: Query<&mut AccessibilityNode, With<Announcer>>,
+        mut tile_trigger_event_reader: EventReader<TileTriggerEvent>,
+        mut covered_before: Local<Option<usize>>,
+    ) {
+        if tile_trigger_event_reader.read().count() > 0 {
+            covered_before.get_or_insert(board.covered_tiles.len());
+            return;
+        }
+        let Some(before) = *covered_before else {
+            return;
+        };
+        if !uncovering.is_empty() {
+            return;
+        }
+
+        *covered_before = None;
+        let count = before.saturating_sub(board.covered_tiles.len());
+        if count > 1 {
+            Self::announce(
+                &mut announcers,
+                localization.text_with("a11y-cascade", &[("count", count.into())]),
+            );
+        }
+    }
+
+    fn announce_results(
+        board: Res<Board>,
+        game_timer: Res<GameTimer>,
+        localization: Res<Localization>,
+        mut announcers: Query<&mut AccessibilityNode, With<Announcer>>,
+        mut mine_explosion_event_reader: EventReader<MineExplosionEvent>,
+        mut board_completed_event_reader: EventReader<BoardCompletedEvent>,
+    ) {
+        for event in mine_explosion_event_reader.read() {
+            let (column, row) = Self::column_and_row(&board, event.coordinates);
+            Self::announce(
+                &mut announcers,
+                localization.text_with(
+                    "a11y-explosion",
+                    &[("column", column.into()), ("row", row.into())],
+                ),
+            );
+        }
+        if board_completed_event_reader.read().count() > 0 {
+            Self::announce(
+                &mut announcers,
+                localization.text_with(
+                    "a11y-victory",
+                    &[("time", Self::format_time(game_timer.elapsed()).into())],
+                ),
+            );
+        }
+    }
+
+    /// This system announces a summary of the board when pressing the button bound to
+    /// `Action::DescribeBoard` (Q by default).
+    fn announce_summary(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        board: Res<Board>,
+        game_timer: Res<GameTimer>,
+        localization: Res<Localization>,
+        mut announcers: Query<&mut AccessibilityNode, With<Announcer>>,
+    ) {
+        if !input_map.just_pressed(Action::DescribeBoard, &keys, &buttons) {
+            return;
+        }
+
+        info!(
+            "[{}] pressed. Describing the board",
+            input_map.label(Action::DescribeBoard)
+        );
+        let summary = localization.text_with(
+            "a11y-summary",
+            &[
+                ("covered", board.covered_tiles.len().into()),
+                ("flags", board.marked_tiles.len().into()),
+                ("mines", board.tile_map.mine_count().into()),
+                ("time", Self::format_time(game_timer.elapsed()).into()),
+            ],
+        );
+        Self::announce(&mut announcers, summary);
+    }
+
+    fn announce(announcers: &mut Query<&mut AccessibilityNode, With<Announcer>>, text: String) {
+        info!("Announcing: {}", text);
+        for mut node in announcers.iter_mut() {
+            node.set_name(text.clone());
+        }
+    }
+
+    fn describe_tile(
+        board: &Board,
+        localization: &Localization,
+        coordinates: Coordinates,
+    ) -> String {
+        let (column, row) = Self::column_and_row(board, coordinates);
+        let (state, count) = if board.covered_tiles.contains_key(&coordinates) {
+            if board.marked_tiles.contains(&coordinates) {
+                ("flagged", 0)
+            } else if board.question_marks.contains(&coordinates) {
+                ("question-mark", 0)
+            } else {
+                ("covered", 0)
+            }
+        } else if board.tile_map.is_mine_at(coordinates) {
+            ("mine", 0)
+        } else {
+            match board.tile_map.mine_count_at(coordinates) {
+                0 => ("empty", 0),
+                count => ("number", count),
+            }
+        };
+
+        localization.text_with(
+            "a11y-tile",
+            &[
+                ("column", column.into()),
+                ("row", row.into()),
+                ("state", state.into()),
+                ("count", count.into()),
+            ],
+        )
+    }
+
+    /// Column and row of a tile as shown to the player, counted from 1 and from the top left.
+    fn column_and_row(board: &Board, coordinates: Coordinates) -> (u16, u16) {
+        (coordinates.x + 1, board.tile_map.height() - coordinates.y)
+    }
+
+    fn color_number_shapes(
+        board_assets: Option<Res<BoardAssets>>,
+        shape_assets: Res<NumberShapeAssets>,
+        mut materials: ResMut<Assets<ColorMaterial>>,
+    ) {
+        let Some(board_assets) = board_assets.filter(|assets| assets.is_changed()) else {
+            return;
+        };
+        for (index, handle) in shape_assets.materials.iter().enumerate() {
+            if let Some(material) = materials.get_mut(handle) {
+                material.color = board_assets.mine_counter_color(index as u8 + 1);
+            }
+        }
+    }
+
+    /// Decorates the numbers of a new board, and redecorates them when the decoration, the theme
+    /// or the tile size changes. Glyphs are rendered at the size of the tiles instead of being
+    /// scaled, which would blur them.
+    fn update_number_shapes(
+        mut commands: Commands,
+        settings: Res<AccessibilitySettings>,
+        (board, board_assets): (Option<Res<Board>>, Option<Res<BoardAssets>>),
+        shape_assets: Res<NumberShapeAssets>,
+        numbers: Query<(Entity, &MineNeighbor)>,
+        shapes: Query<Entity, With<NumberShape>>,
+        mut tile_size: Local<f32>,
+    ) {
+        let (Some(board), Some(board_assets)) = (board, board_assets) else {
+            return;
+        };
+        let resized = board.tile_size != *tile_size;
+        if !board.is_added() && !settings.is_changed() && !board_assets.is_changed() && !resized {
+            return;
+        }
+        *tile_size = board.tile_size;
+
+        for entity in shapes.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        if settings.number_decoration == NumberDecoration::None {
+            return;
+        }
+        for (entity, neighbor) in numbers.iter() {
+            commands.entity(entity).with_children(|parent| {
+                Self::spawn_number_shape(
+                    parent,
+                    settings.number_decoration,
+                    &shape_assets,
+                    &board_assets,
+                    neighbor.count,
+                    board.tile_size,
+                );
+            });
+        }
+    }
+
+    fn spawn_number_shape(
+        parent: &mut ChildBuilder,
+        decoration: NumberDecoration,
+        shape_assets: &NumberShapeAssets,
+        board_assets: &BoardAssets,
+        count: u8,
+        tile_size: f32,
+    ) {
+        let center = (PATTERN_CENTER * tile_size).extend(PATTERN_Z);
+        match decoration {
+            NumberDecoration::None => {}
+            NumberDecoration::Dice => {
+                let index = (count.clamp(1, MAX_COUNT) - 1) as usize;
+                parent
+                    .spawn((
+                        Name::new("Number Shape"),
+                        NumberShape,
+                        SpatialBundle {
+                            transform: Transform::from_translation(center)
+                                .with_scale(Vec3::new(tile_size, tile_size, 1f32)),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|shape| {
+                        for (x, y) in NumberDecoration::dice_pips(count) {
+                            let position = Vec2::new(*x as f32, *y as f32) * PIP_SPACING;
+                            shape.spawn(MaterialMesh2dBundle {
+                                mesh: shape_assets.mesh.clone(),
+                                material: shape_assets.materials[index].clone(),
+                                transform: Transform::from_translation(position.extend(0f32)),
+                                ..default()
+                            });
+                        }
+                    });
+            }
+            NumberDecoration::Glyphs => {
+                parent.spawn((
+                    Name::new("Number Shape"),
+                    NumberShape,
+                    Text2dBundle {
+                        text: Text::from_section(
+                            board_assets.number_glyph(count),
+                            TextStyle {
+                                font: board_assets.number_glyph_font.clone(),
+                                font_size: tile_size * GLYPH_SIZE,
+                                color: board_assets.mine_counter_color(count),
+                            },
+                        )
+                        .with_alignment(TextAlignment::Center),
+                        transform: Transform::from_translation(center),
+                        ..default()
+                    },
+                ));
+            }
+        }
+    }
+
+    fn format_time(elapsed: Duration) -> String {
+        let seconds = elapsed.as_secs();
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
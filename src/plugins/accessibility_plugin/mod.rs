@@ -0,0 +1,4 @@
+pub use accessibility_plugin::AccessibilityPlugin;
+
+#[allow(clippy::module_inception)]
+mod accessibility_plugin;
@@ -0,0 +1,229 @@
+use bevy::prelude::*;
+
+use crate::components::{
+    BoardElement, Coordinates, Mine, TileAnimation, TileAnimationKind, Uncover,
+};
+use crate::plugins::{InputSet, TileTriggerEvent};
+use crate::resources::{Action, AnimationSettings, Board, InputMap, UncoverAnimation};
+use crate::systems::{trigger_event_handler, uncover_tiles};
+use crate::AppState;
+
+/// Seconds for a cover to fade or flip away.
+const UNCOVER_DURATION: f32 = 0.25;
+/// Delay in seconds added per tile of distance from the clicked tile in a cascade.
+const RIPPLE_STEP: f32 = 0.03;
+/// Seconds for a flag to drop onto its tile.
+const FLAG_DROP_DURATION: f32 = 0.2;
+/// Scale a flag starts dropping from.
+const FLAG_DROP_SCALE: f32 = 1.8;
+/// Delay in seconds added per tile of distance from the detonated mine.
+const SHOCKWAVE_STEP: f32 = 0.08;
+/// Seconds for a mine cover to fade away once the shockwave reaches it.
+const REVEAL_DURATION: f32 = 0.3;
+
+/// Animates uncovering, flagging and explosions. Animations only affect how the board is drawn:
+/// the board state is updated right away and uncovered covers are replaced by animated copies.
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AnimationSettings>();
+
+        app.add_systems(Update, Self::toggle_animations.in_set(InputSet::Shortcuts));
+        app.add_systems(
+            Update,
+            (
+                Self::animate_uncovered_tiles
+                    .after(trigger_event_handler)
+                    .before(uncover_tiles),
+                Self::animate_flags,
+                Self::run_animations,
+            )
+                .run_if(in_state(AppState::InGame)),
+        );
+
+        info!("Loaded Animation Plugin");
+    }
+}
+
+impl AnimationPlugin {
+    /// This system toggles and saves the animations when pressing the button bound to
+    /// `Action::ToggleAnimations` (N by default).
+    fn toggle_animations(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        mut settings: ResMut<AnimationSettings>,
+    ) {
+        if input_map.just_pressed(Action::ToggleAnimations, &keys, &buttons) {
+            settings.toggle();
+            info!(
+                "[{}] pressed. Animations are now {}",
+                input_map.label(Action::ToggleAnimations),
+                if settings.is_enabled() { "on" } else { "off" }
+            );
+            settings.save();
+        }
+    }
+
+    /// Spawns an animated copy of every cover about to be despawned by `uncover_tiles`, delayed
+    /// by its distance from the clicked tile so cascades ripple outwards. Uncovering a mine sends
+    /// a shockwave revealing the other mines.
+    fn animate_uncovered_tiles(
+        mut commands: Commands,
+        settings: Res<AnimationSettings>,
+        board: Option<Res<Board>>,
+        covers: Query<(&Parent, &Sprite, &Handle<Image>, &Transform), With<Uncover>>,
+        tiles: Query<(&Coordinates, Option<&Mine>)>,
+        mut tile_trigger_event_reader: EventReader<TileTriggerEvent>,
+        mut ripple_origin: Local<Option<Coordinates>>,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        if board.is_added() {
+            *ripple_origin = None;
+        }
+        if let Some(event) = tile_trigger_event_reader.read().last() {
+            *ripple_origin = Some(event.coordinates);
+        }
+        if !settings.is_enabled() {
+            return;
+        }
+
+        for (parent, sprite, texture, transform) in covers.iter() {
+            let Ok((coordinates, mine)) = tiles.get(parent.get()) else {
+                continue;
+            };
+            let origin = *ripple_origin.get_or_insert(*coordinates);
+
+            let kind = match settings.uncover {
+                UncoverAnimation::Fade => TileAnimationKind::Fade,
+                UncoverAnimation::Flip => TileAnimationKind::Flip,
+            };
+            let delay = distance(origin, *coordinates) as f32 * RIPPLE_STEP;
+            commands.entity(parent.get()).with_children(|parent| {
+                parent.spawn((
+                    Name::new("Uncovered Tile Cover"),
+                    SpriteBundle {
+                        sprite: sprite.clone(),
+                        texture: texture.clone(),
+                        transform: *transform,
+                        ..default()
+                    },
+                    TileAnimation::new(
+                        kind,
+                        settings.scaled(delay),
+                        settings.scaled(UNCOVER_DURATION),
+                    ),
+                ));
+            });
+
+            if mine.is_some() {
+                Self::send_shockwave(&mut commands, &settings, &board, *coordinates);
+            }
+        }
+    }
+
+    fn send_shockwave(
+        commands: &mut Commands,
+        settings: &AnimationSettings,
+        board: &Board,
+        detonated: Coordinates,
+    ) {
+        for coordinates in board.tile_map.mines() {
+            // Flagged mines keep their cover so the flag stays visible
+            if coordinates == detonated || board.marked_tiles.contains(&coordinates) {
+                continue;
+            }
+            let Some(cover) = board.covered_tiles.get(&coordinates) else {
+                continue;
+            };
+            let delay = distance(detonated, coordinates) as f32 * SHOCKWAVE_STEP;
+            commands.entity(*cover).insert(TileAnimation::new(
+                TileAnimationKind::Reveal,
+                settings.scaled(delay),
+                settings.scaled(REVEAL_DURATION),
+            ));
+        }
+    }
+
+    fn animate_flags(
+        mut commands: Commands,
+        settings: Res<AnimationSettings>,
+        flags: Query<(Entity, &BoardElement), Added<BoardElement>>,
+    ) {
+        if !settings.is_enabled() {
+            return;
+        }
+
+        for (entity, element) in flags.iter() {
+            if *element == BoardElement::Flag {
+                commands.entity(entity).insert(TileAnimation::new(
+                    TileAnimationKind::Drop,
+                    0f32,
+                    settings.scaled(FLAG_DROP_DURATION),
+                ));
+            }
+        }
+    }
+
+    /// Advances the running animations. Disabling animations finishes them right away.
+    fn run_animations(
+        mut commands: Commands,
+        time: Res<Time>,
+        settings: Res<AnimationSettings>,
+        mut animations: Query<(
+            Entity,
+            &mut TileAnimation,
+            &mut Sprite,
+            &mut Transform,
+            &mut Visibility,
+        )>,
+    ) {
+        for (entity, mut animation, mut sprite, mut transform, mut visibility) in
+            animations.iter_mut()
+        {
+            animation.elapsed = if settings.is_enabled() {
+                animation.elapsed + time.delta_seconds()
+            } else {
+                f32::INFINITY
+            };
+            let progress = animation.progress();
+
+            match animation.kind {
+                TileAnimationKind::Fade | TileAnimationKind::Reveal => {
+                    sprite.color.set_a(1f32 - progress);
+                }
+                TileAnimationKind::Flip => {
+                    transform.scale.x = (progress * 90f32).to_radians().cos();
+                }
+                TileAnimationKind::Drop => {
+                    sprite.color.set_a(progress);
+                    transform.scale =
+                        Vec3::splat(FLAG_DROP_SCALE - (FLAG_DROP_SCALE - 1f32) * progress);
+                }
+            }
+
+            if animation.is_finished() {
+                match animation.kind {
+                    TileAnimationKind::Fade | TileAnimationKind::Flip => {
+                        commands.entity(entity).despawn_recursive();
+                    }
+                    TileAnimationKind::Drop => {
+                        commands.entity(entity).remove::<TileAnimation>();
+                    }
+                    TileAnimationKind::Reveal => {
+                        *visibility = Visibility::Hidden;
+                        commands.entity(entity).remove::<TileAnimation>();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Number of tiles between two coordinates, counting diagonal steps as one.
+fn distance(a: Coordinates, b: Coordinates) -> u16 {
+    a.x.abs_diff(b.x).max(a.y.abs_diff(b.y))
+}
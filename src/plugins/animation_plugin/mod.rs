@@ -0,0 +1,4 @@
+pub use animation_plugin::AnimationPlugin;
+
+#[allow(clippy::module_inception)]
+mod animation_plugin;
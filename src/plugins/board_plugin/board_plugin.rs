@@ -1,35 +1,74 @@
 use bevy::prelude::*;
 use bevy::utils::HashMap;
-use bevy::window::PrimaryWindow;
+use bevy::window::{PrimaryWindow, WindowResized};
 
-use crate::components::{Coordinates, Mine, MineNeighbor, Uncover};
+use crate::components::{BoardElement, Coordinates, Mine, MineNeighbor, Uncover};
 use crate::plugins::{
     BoardCompletedEvent, Bounds2, MineExplosionEvent, TileMarkEvent, TileTriggerEvent,
 };
-use crate::resources::{Board, BoardAssets, BoardOptions, BoardPosition, Tile, TileMap, TileSize};
-use crate::systems::{handle_mouse_input, mark_tiles, trigger_event_handler, uncover_tiles};
-use crate::AppState;
+use crate::resources::{
+    Board, BoardAssets, BoardLayout, BoardMask, BoardOptions, BoardPosition, GameTimer,
+    GameplaySettings, Tile, TileMap, TileSize,
+};
+use crate::systems::{
+    export_board, handle_mouse_input, mark_tiles, stop_game_timer, tick_game_timer,
+    track_mouse_tile, trigger_event_handler, uncover_tiles,
+};
 
 pub struct BoardPlugin<T> {
     pub running_state: T,
+    /// States in which a started board is kept, hidden, so the game can be resumed from them.
+    /// Entering any other state from the running state discards the board.
+    pub paused_states: Vec<T>,
 }
 
 impl<T: States> Plugin for BoardPlugin<T> {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(self.running_state.clone()), Self::create_board);
+        let board_states: Vec<T> = std::iter::once(self.running_state.clone())
+            .chain(self.paused_states.iter().cloned())
+            .collect();
+        let keeps_board = {
+            let board_states = board_states.clone();
+            move |state: Res<State<T>>| board_states.contains(state.get())
+        };
+
+        app.insert_resource(GameplaySettings::load());
+
+        app.add_systems(
+            OnEnter(self.running_state.clone()),
+            (
+                Self::create_board.run_if(not(resource_exists::<Board>())),
+                Self::show_board,
+            ),
+        );
 
         app.add_systems(
             Update,
             (
-                handle_mouse_input,
+                (track_mouse_tile, handle_mouse_input).chain(),
                 trigger_event_handler,
                 uncover_tiles,
                 mark_tiles,
+                export_board,
+                tick_game_timer,
+                stop_game_timer.after(uncover_tiles),
             )
-                .run_if(in_state(AppState::InGame)),
+                .run_if(in_state(self.running_state.clone())),
         );
 
-        app.add_systems(OnExit(self.running_state.clone()), Self::cleanup_board);
+        // Also keeps boards shown outside of the game, like the editor preview, in shape
+        app.add_systems(Update, Self::relayout_board);
+
+        app.add_systems(
+            OnExit(self.running_state.clone()),
+            Self::hide_board.run_if(keeps_board.clone()),
+        );
+        for state in board_states {
+            app.add_systems(
+                OnExit(state),
+                Self::cleanup_board.run_if(not(keeps_board.clone())),
+            );
+        }
 
         app.add_event::<TileTriggerEvent>();
         app.add_event::<TileMarkEvent>();
@@ -44,8 +83,11 @@ impl<T: States> BoardPlugin<T> {
     pub fn create_board(
         mut commands: Commands,
         board_options: Option<Res<BoardOptions>>,
+        board_layout: Option<Res<BoardLayout>>,
+        board_mask: Option<Res<BoardMask>>,
         window_query: Query<&Window, With<PrimaryWindow>>,
         board_assets: Res<BoardAssets>,
+        mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
     ) {
         let window = window_query.single();
 
@@ -54,39 +96,70 @@ impl<T: States> BoardPlugin<T> {
             None => BoardOptions::default(),
         };
 
-        let (map_size_x, map_size_y) = options.map_size;
-        let mut tile_map = TileMap::new_empty(map_size_x, map_size_y);
-        tile_map.set_mines(options.mine_count);
+        let tile_map = match &board_layout {
+            Some(layout) => layout.tile_map.clone(),
+            None => {
+                let mut tile_map = match board_mask {
+                    Some(mask) => TileMap::new_masked(mask.clone()),
+                    None => {
+                        let (map_size_x, map_size_y) = options.map_size;
+                        TileMap::new_empty(map_size_x, map_size_y)
+                    }
+                };
+                tile_map.set_mines(options.mine_count);
+                tile_map
+            }
+        };
 
         #[cfg(feature = "debug")]
         info!("{}", tile_map.console_output());
 
-        let tile_size = match options.tile_size {
-            TileSize::Fixed(size) => size,
-            TileSize::WindowAdaptive { min, max } => {
-                Self::adaptive_tile_size(window, (min, max), (tile_map.width(), tile_map.height()))
+        let (board, safe_start) =
+            Self::spawn_board(&mut commands, tile_map, &options, window, &board_assets);
+
+        let has_initial_state = match &board_layout {
+            Some(layout) => {
+                for coordinates in layout.revealed.iter() {
+                    if let Some(entity) = board.covered_tiles.get(coordinates) {
+                        commands.entity(*entity).insert(Uncover);
+                    }
+                }
+                tile_mark_event_writer
+                    .send_batch(layout.flagged.iter().copied().map(TileMarkEvent));
+                layout.has_initial_state()
             }
+            None => false,
         };
 
-        let board_size = Vec2::new(
-            tile_map.width() as f32 * tile_size,
-            tile_map.height() as f32 * tile_size,
-        );
-        info!("Board size: {}", board_size);
-
-        let board_position = match options.position {
-            BoardPosition::Centered { offset } => {
-                Vec3::new(-(board_size.x / 2f32), -(board_size.y / 2f32), 0f32) + offset
+        if options.safe_start_enabled && !has_initial_state {
+            if let Some(entity) = safe_start {
+                commands.entity(entity).insert(Uncover);
             }
-            BoardPosition::CustomPosition(pos) => pos,
-        };
+        }
+
+        commands.insert_resource(board);
+        commands.insert_resource(GameTimer::default());
+    }
+
+    /// Spawns the board entities for a tile map and returns the matching `Board` along with the
+    /// cover of the first empty tile, used for the safe start.
+    pub fn spawn_board(
+        commands: &mut Commands,
+        tile_map: TileMap,
+        options: &BoardOptions,
+        window: &Window,
+        board_assets: &BoardAssets,
+    ) -> (Board, Option<Entity>) {
+        let (tile_size, board_size, board_position) =
+            Self::layout(options, window, (tile_map.width(), tile_map.height()));
+        info!("Board size: {}", board_size);
 
         let mut covered_tiles =
             HashMap::with_capacity((tile_map.width() * tile_map.height()).into());
 
         let mut safe_start = None;
 
-        info!("Spawning board");
+        info!("Spawning board using '{}' assets", board_assets.label);
         let board_entity = commands
             .spawn((
                 Name::new("Board"),
@@ -96,42 +169,41 @@ impl<T: States> BoardPlugin<T> {
                 },
             ))
             .with_children(|parent| {
-                parent
-                    .spawn(SpriteBundle {
-                        sprite: Sprite {
-                            color: board_assets.board_material.color,
-                            custom_size: Some(board_size),
+                // A full background would fill the holes of shaped boards, so it is only spawned
+                // for rectangular ones
+                if tile_map.is_rectangle() {
+                    parent
+                        .spawn(SpriteBundle {
+                            sprite: Sprite {
+                                color: board_assets.board_material.color,
+                                custom_size: Some(board_size),
+                                ..default()
+                            },
+                            texture: board_assets.board_material.texture.clone(),
+                            transform: Transform::from_xyz(
+                                board_size.x / 2f32,
+                                board_size.y / 2f32,
+                                0f32,
+                            ),
                             ..default()
-                        },
-                        texture: board_assets.board_material.texture.clone(),
-                        transform: Transform::from_xyz(
-                            board_size.x / 2f32,
-                            board_size.y / 2f32,
-                            0f32,
-                        ),
-                        ..default()
-                    })
-                    .insert(Name::new("Background"));
+                        })
+                        .insert(Name::new("Background"))
+                        .insert(BoardElement::Background);
+                }
 
                 Self::spawn_tiles(
                     parent,
                     &tile_map,
                     tile_size,
                     options.tile_padding,
-                    &board_assets,
+                    board_assets,
                     &mut covered_tiles,
                     &mut safe_start,
                 );
             })
             .id();
 
-        if options.safe_start_enabled {
-            if let Some(entity) = safe_start {
-                commands.entity(entity).insert(Uncover);
-            }
-        }
-
-        commands.insert_resource(Board {
+        let board = Board {
             tile_map,
             bounds: Bounds2 {
                 position: board_position.xy(),
@@ -141,7 +213,10 @@ impl<T: States> BoardPlugin<T> {
             covered_tiles,
             entity: board_entity,
             marked_tiles: Vec::new(),
-        });
+            question_marks: Vec::new(),
+        };
+
+        (board, safe_start)
     }
 
     fn spawn_tiles(
@@ -160,6 +235,10 @@ impl<T: States> BoardPlugin<T> {
                     y: y as u16,
                 };
 
+                if !tile_map.has_tile_at(coordinates) {
+                    continue;
+                }
+
                 let mut commands = parent.spawn(SpriteBundle {
                     sprite: Sprite {
                         color: board_assets.tile_material.color,
@@ -177,6 +256,7 @@ impl<T: States> BoardPlugin<T> {
 
                 commands
                     .insert(Name::new(format!("Tile ({}, {})", x, y)))
+                    .insert(BoardElement::Tile)
                     .insert(coordinates);
 
                 commands.with_children(|parent| {
@@ -192,6 +272,7 @@ impl<T: States> BoardPlugin<T> {
                             ..default()
                         })
                         .insert(Name::new("Tile Cover"))
+                        .insert(BoardElement::Cover)
                         .id();
                     covered_tiles.insert(coordinates, entity);
 
@@ -204,24 +285,31 @@ impl<T: States> BoardPlugin<T> {
                     Tile::Mine => {
                         commands.insert(Mine);
                         commands.with_children(|parent| {
-                            parent.spawn(SpriteBundle {
-                                sprite: Sprite {
-                                    custom_size: Some(Vec2::splat(tile_size - tile_padding)),
+                            parent.spawn((
+                                BoardElement::Mine,
+                                SpriteBundle {
+                                    sprite: Sprite {
+                                        custom_size: Some(Vec2::splat(tile_size - tile_padding)),
+                                        color: board_assets.mine_material.color,
+                                        ..default()
+                                    },
+                                    transform: Transform::from_xyz(0f32, 0f32, 1f32),
+                                    texture: board_assets.mine_material.texture.clone(),
                                     ..default()
                                 },
-                                transform: Transform::from_xyz(0f32, 0f32, 1f32),
-                                texture: board_assets.mine_material.texture.clone(),
-                                ..default()
-                            });
+                            ));
                         });
                     }
                     Tile::MineNeighbor(mine_count) => {
                         commands.insert(MineNeighbor { count: *mine_count });
                         commands.with_children(|parent| {
-                            parent.spawn(Self::mine_count_text_bundle(
-                                *mine_count,
-                                board_assets,
-                                tile_size - tile_padding,
+                            parent.spawn((
+                                BoardElement::MineCounter(*mine_count),
+                                Self::mine_count_text_bundle(
+                                    *mine_count,
+                                    board_assets,
+                                    tile_size - tile_padding,
+                                ),
                             ));
                         });
                     }
@@ -254,6 +342,125 @@ impl<T: States> BoardPlugin<T> {
         }
     }
 
+    /// Tile size, board size and board position for a map of the given size.
+    fn layout(options: &BoardOptions, window: &Window, map_size: (u16, u16)) -> (f32, Vec2, Vec3) {
+        let tile_size = match options.tile_size {
+            TileSize::Fixed(size) => size,
+            TileSize::WindowAdaptive { min, max } => {
+                Self::adaptive_tile_size(window, (min, max), map_size)
+            }
+        };
+
+        let board_size = Vec2::new(map_size.0 as f32, map_size.1 as f32) * tile_size;
+
+        let board_position = match options.position {
+            BoardPosition::Centered { offset } => {
+                Vec3::new(-(board_size.x / 2f32), -(board_size.y / 2f32), 0f32) + offset
+            }
+            BoardPosition::CustomPosition(pos) => pos,
+        };
+
+        (tile_size, board_size, board_position)
+    }
+
+    /// Recomputes the layout when the window is resized or the board options change, and moves
+    /// and rescales the existing board entities, keeping the state of the game.
+    #[allow(clippy::type_complexity)]
+    fn relayout_board(
+        board: Option<ResMut<Board>>,
+        board_options: Option<Res<BoardOptions>>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+        mut boards: Query<&mut Transform, Without<BoardElement>>,
+        mut elements: Query<(
+            &BoardElement,
+            &mut Transform,
+            Option<&Coordinates>,
+            Option<&mut Sprite>,
+            Option<&mut Text>,
+        )>,
+        mut window_resized_event_reader: EventReader<WindowResized>,
+    ) {
+        let resized = window_resized_event_reader.read().count() > 0;
+        let options_changed = board_options
+            .as_ref()
+            .is_some_and(|options| options.is_changed() && !options.is_added());
+        if !resized && !options_changed {
+            return;
+        }
+        let Some(mut board) = board else {
+            return;
+        };
+        let Ok(window) = window_query.get_single() else {
+            return;
+        };
+        // Minimized windows report a zero size
+        if window.width() <= 0f32 || window.height() <= 0f32 {
+            return;
+        }
+
+        let options = board_options.map(|o| *o).unwrap_or_default();
+        let map_size = (board.tile_map.width(), board.tile_map.height());
+        let (tile_size, board_size, board_position) = Self::layout(&options, window, map_size);
+        if !options_changed
+            && tile_size == board.tile_size
+            && board_position.xy() == board.bounds.position
+        {
+            return;
+        }
+
+        info!("Board layout changed, new tile size: {}", tile_size);
+        board.tile_size = tile_size;
+        board.bounds = Bounds2 {
+            position: board_position.xy(),
+            size: board_size,
+        };
+
+        if let Ok(mut transform) = boards.get_mut(board.entity) {
+            transform.translation = board_position;
+        }
+
+        let sprite_size = tile_size - options.tile_padding;
+        for (element, mut transform, coordinates, sprite, text) in elements.iter_mut() {
+            match element {
+                BoardElement::Background => {
+                    transform.translation = (board_size / 2f32).extend(transform.translation.z);
+                    if let Some(mut sprite) = sprite {
+                        sprite.custom_size = Some(board_size);
+                    }
+                }
+                BoardElement::Tile => {
+                    if let Some(coordinates) = coordinates {
+                        let position = Vec2::new(coordinates.x as f32, coordinates.y as f32)
+                            * tile_size
+                            + tile_size / 2f32;
+                        transform.translation = position.extend(transform.translation.z);
+                    }
+                    if let Some(mut sprite) = sprite {
+                        sprite.custom_size = Some(Vec2::splat(sprite_size));
+                    }
+                }
+                BoardElement::Cover | BoardElement::Mine => {
+                    if let Some(mut sprite) = sprite {
+                        sprite.custom_size = Some(Vec2::splat(sprite_size));
+                    }
+                }
+                // Flags cover the whole tile, see `mark_tiles`
+                BoardElement::Flag => {
+                    if let Some(mut sprite) = sprite {
+                        sprite.custom_size = Some(Vec2::splat(tile_size));
+                    }
+                }
+                BoardElement::MineCounter(_) | BoardElement::QuestionMark => {
+                    if let Some(mut text) = text {
+                        for section in text.sections.iter_mut() {
+                            section.style.font_size = sprite_size;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     fn adaptive_tile_size(
         window: &Window,
         (min, max): (f32, f32),
@@ -264,8 +471,35 @@ impl<T: States> BoardPlugin<T> {
         max_width.min(max_height).clamp(min, max)
     }
 
-    fn cleanup_board(board: Res<Board>, mut commands: Commands) {
+    fn show_board(board: Option<Res<Board>>, mut visibilities: Query<&mut Visibility>) {
+        if let Some(mut visibility) =
+            board.and_then(|board| visibilities.get_mut(board.entity).ok())
+        {
+            *visibility = Visibility::Inherited;
+        }
+    }
+
+    /// Hides a board left for a paused state, so pausing can not be used to study it.
+    fn hide_board(board: Option<Res<Board>>, mut visibilities: Query<&mut Visibility>) {
+        if let Some(mut visibility) =
+            board.and_then(|board| visibilities.get_mut(board.entity).ok())
+        {
+            info!("Hiding the board until the game is resumed");
+            *visibility = Visibility::Hidden;
+        }
+    }
+
+    fn cleanup_board(board: Option<Res<Board>>, mut commands: Commands) {
+        if let Some(board) = board {
+            Self::discard_board(&mut commands, &board);
+        }
+    }
+
+    /// Despawns the board and removes it, the next time the running state is entered a new board
+    /// is generated.
+    pub fn discard_board(commands: &mut Commands, board: &Board) {
         info!("Performing recursive despawn of entities");
         commands.entity(board.entity).despawn_recursive();
+        commands.remove_resource::<Board>();
     }
 }
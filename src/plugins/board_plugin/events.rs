@@ -14,4 +14,6 @@ pub struct BoardCompletedEvent;
 pub struct TileMarkEvent(pub Coordinates);
 
 #[derive(Debug, Copy, Clone, Event)]
-pub struct MineExplosionEvent;
+pub struct MineExplosionEvent {
+    pub coordinates: Coordinates,
+}
@@ -5,6 +5,7 @@ pub use events::MineExplosionEvent;
 pub use events::TileMarkEvent;
 pub use events::TileTriggerEvent;
 
+#[allow(clippy::module_inception)]
 mod board_plugin;
 mod bounds;
 mod events;
@@ -0,0 +1,285 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::components::CameraShake;
+use crate::plugins::InputSet;
+use crate::resources::{Action, Board, InputMap};
+use crate::systems::Minesweeper2dCamera;
+
+const MIN_ZOOM: f32 = 0.2;
+const MAX_ZOOM: f32 = 8f32;
+/// Zoom factor applied per mouse wheel line or key press.
+const ZOOM_STEP: f32 = 1.1;
+/// Mouse wheel pixels counted as one line, for touchpads.
+const PIXELS_PER_LINE: f32 = 50f32;
+/// Keyboard panning speed in screen pixels per second.
+const PAN_SPEED: f32 = 600f32;
+/// Space left around the board when zooming to fit.
+const FIT_MARGIN: f32 = 1.05;
+
+/// Mouse wheel zoom around the cursor, drag and keyboard panning, and zoom-to-fit for boards
+/// that do not fit the window.
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                Self::fit_new_board,
+                (
+                    Self::zoom_with_mouse_wheel,
+                    Self::zoom_with_keyboard,
+                    Self::pan_with_mouse,
+                    Self::pan_with_keyboard,
+                )
+                    .chain()
+                    .in_set(InputSet::Camera),
+            )
+                .chain(),
+        );
+
+        info!("Loaded Camera Plugin");
+    }
+}
+
+impl CameraPlugin {
+    /// Shows the whole of a new board, only zooming out when it does not fit the window.
+    fn fit_new_board(
+        board: Option<Res<Board>>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+        mut camera_query: Query<
+            (
+                &mut Transform,
+                &mut OrthographicProjection,
+                Option<&mut CameraShake>,
+            ),
+            With<Minesweeper2dCamera>,
+        >,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        if !board.is_added() {
+            return;
+        }
+        let Ok(window) = window_query.get_single() else {
+            return;
+        };
+
+        for (mut transform, mut projection, shake) in camera_query.iter_mut() {
+            let scale = Self::fit_scale(&board, window).max(1f32);
+            Self::look_at_board(&board, scale, &mut transform, &mut projection, shake);
+        }
+    }
+
+    /// This system zooms around the cursor when scrolling the mouse wheel.
+    fn zoom_with_mouse_wheel(
+        window_query: Query<&Window, With<PrimaryWindow>>,
+        mut camera_query: Query<
+            (
+                &Camera,
+                &GlobalTransform,
+                &mut Transform,
+                &mut OrthographicProjection,
+            ),
+            With<Minesweeper2dCamera>,
+        >,
+        mut mouse_wheel_event_reader: EventReader<MouseWheel>,
+    ) {
+        let lines: f32 = mouse_wheel_event_reader
+            .read()
+            .map(|event| match event.unit {
+                MouseScrollUnit::Line => event.y,
+                MouseScrollUnit::Pixel => event.y / PIXELS_PER_LINE,
+            })
+            .sum();
+        if lines == 0f32 {
+            return;
+        }
+        let Ok(window) = window_query.get_single() else {
+            return;
+        };
+
+        for (camera, camera_transform, mut transform, mut projection) in camera_query.iter_mut() {
+            match window
+                .cursor_position()
+                .and_then(|position| camera.viewport_to_world_2d(camera_transform, position))
+            {
+                Some(cursor) => Self::zoom_around(&mut transform, &mut projection, cursor, lines),
+                None => Self::zoom(&mut projection, lines),
+            }
+        }
+    }
+
+    /// This system zooms with the buttons bound to `Action::ZoomIn` and `Action::ZoomOut` (+ and -
+    /// by default), and zooms to fit the board with `Action::ZoomToFit` (Z by default).
+    fn zoom_with_keyboard(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        board: Option<Res<Board>>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+        mut camera_query: Query<
+            (
+                &mut Transform,
+                &mut OrthographicProjection,
+                Option<&mut CameraShake>,
+            ),
+            With<Minesweeper2dCamera>,
+        >,
+    ) {
+        let zoom_in = input_map.just_pressed(Action::ZoomIn, &keys, &buttons);
+        let zoom_out = input_map.just_pressed(Action::ZoomOut, &keys, &buttons);
+        let zoom_to_fit = input_map.just_pressed(Action::ZoomToFit, &keys, &buttons);
+
+        for (mut transform, mut projection, shake) in camera_query.iter_mut() {
+            if zoom_in {
+                Self::zoom(&mut projection, 1f32);
+            }
+            if zoom_out {
+                Self::zoom(&mut projection, -1f32);
+            }
+
+            if zoom_to_fit {
+                let (Some(board), Ok(window)) = (&board, window_query.get_single()) else {
+                    continue;
+                };
+                info!(
+                    "[{}] pressed. Zooming to fit the board",
+                    input_map.label(Action::ZoomToFit)
+                );
+                let scale = Self::fit_scale(board, window);
+                Self::look_at_board(board, scale, &mut transform, &mut projection, shake);
+            }
+        }
+    }
+
+    /// This system pans the camera while dragging with the button bound to `Action::PanCamera`
+    /// (the middle mouse button by default) held.
+    fn pan_with_mouse(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+        mut camera_query: Query<
+            (&mut Transform, &OrthographicProjection),
+            With<Minesweeper2dCamera>,
+        >,
+        mut last_cursor_position: Local<Option<Vec2>>,
+    ) {
+        let cursor = window_query
+            .get_single()
+            .ok()
+            .and_then(|window| window.cursor_position());
+
+        if input_map.pressed(Action::PanCamera, &keys, &buttons) {
+            if let (Some(cursor), Some(last)) = (cursor, *last_cursor_position) {
+                // Screen coordinates grow downwards, world coordinates upwards
+                let delta = (cursor - last) * Vec2::new(-1f32, 1f32);
+                for (mut transform, projection) in camera_query.iter_mut() {
+                    transform.translation += (delta * projection.scale).extend(0f32);
+                }
+            }
+            *last_cursor_position = cursor;
+        } else {
+            *last_cursor_position = None;
+        }
+    }
+
+    /// This system pans the camera with the keys bound to the cursor actions while holding Shift,
+    /// without Shift they move the keyboard cursor.
+    fn pan_with_keyboard(
+        input_map: Res<InputMap>,
+        input: Res<Input<KeyCode>>,
+        time: Res<Time>,
+        mut camera_query: Query<
+            (&mut Transform, &OrthographicProjection),
+            With<Minesweeper2dCamera>,
+        >,
+    ) {
+        if !input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            return;
+        }
+
+        let mut direction = Vec2::ZERO;
+        if input.any_pressed(input_map.keys(Action::CursorLeft)) {
+            direction.x -= 1f32;
+        }
+        if input.any_pressed(input_map.keys(Action::CursorRight)) {
+            direction.x += 1f32;
+        }
+        if input.any_pressed(input_map.keys(Action::CursorDown)) {
+            direction.y -= 1f32;
+        }
+        if input.any_pressed(input_map.keys(Action::CursorUp)) {
+            direction.y += 1f32;
+        }
+        if direction == Vec2::ZERO {
+            return;
+        }
+
+        for (mut transform, projection) in camera_query.iter_mut() {
+            Self::pan(&mut transform, projection, direction.normalize(), &time);
+        }
+    }
+
+    /// Zooms in by the given number of steps, or out for negative steps.
+    pub fn zoom(projection: &mut OrthographicProjection, steps: f32) {
+        projection.scale = (projection.scale * ZOOM_STEP.powf(-steps)).clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+
+    /// Zooms like `zoom`, keeping the given world point in place on the screen.
+    pub fn zoom_around(
+        transform: &mut Transform,
+        projection: &mut OrthographicProjection,
+        point: Vec2,
+        steps: f32,
+    ) {
+        let old_scale = projection.scale;
+        Self::zoom(projection, steps);
+
+        let offset = transform.translation.truncate() - point;
+        let translation = point + offset * (projection.scale / old_scale);
+        transform.translation = translation.extend(transform.translation.z);
+    }
+
+    /// Zoom steps matching a change of the zoom by the given factor, above 1 zooms in.
+    pub fn zoom_steps(factor: f32) -> f32 {
+        factor.ln() / ZOOM_STEP.ln()
+    }
+
+    /// Pans at the keyboard panning speed for this frame, the direction length scales the speed.
+    pub fn pan(
+        transform: &mut Transform,
+        projection: &OrthographicProjection,
+        direction: Vec2,
+        time: &Time,
+    ) {
+        let distance = PAN_SPEED * projection.scale * time.delta_seconds();
+        transform.translation += (direction * distance).extend(0f32);
+    }
+
+    /// Scale at which the whole board fits the window.
+    fn fit_scale(board: &Board, window: &Window) -> f32 {
+        let scale = board.bounds.size / Vec2::new(window.width(), window.height());
+        (scale.max_element() * FIT_MARGIN).clamp(MIN_ZOOM, MAX_ZOOM)
+    }
+
+    fn look_at_board(
+        board: &Board,
+        scale: f32,
+        transform: &mut Transform,
+        projection: &mut OrthographicProjection,
+        shake: Option<Mut<CameraShake>>,
+    ) {
+        let center = board.bounds.position + board.bounds.size / 2f32;
+        transform.translation = center.extend(transform.translation.z);
+        projection.scale = scale;
+        // The shake would otherwise undo an offset that is no longer applied
+        if let Some(mut shake) = shake {
+            shake.offset = Vec2::ZERO;
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use camera_plugin::CameraPlugin;
+
+#[allow(clippy::module_inception)]
+mod camera_plugin;
@@ -0,0 +1,357 @@
+use bevy::prelude::*;
+
+use crate::components::{
+    BindingText, ControlsScreen, ControlsStatusText, RebindButton, ResetBindingsButton,
+};
+use crate::resources::{Action, InputBinding, InputMap, Localization, RebindCapture};
+use crate::systems::{
+    text_style, BUTTON_COLOR, BUTTON_HOVERED_COLOR, MENU_BACKGROUND_COLOR, UI_FONT_PATH,
+};
+use crate::AppState;
+
+const CAPTURING_COLOR: Color = Color::rgb(0.3, 0.3, 0.45);
+const CONFLICT_TEXT_COLOR: Color = Color::RED;
+
+/// Systems reacting to bound actions outside of a single screen, gated by the `ControlsPlugin`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, SystemSet)]
+pub enum InputSet {
+    /// Shortcuts working on every screen but the controls screen, where pressed keys and buttons
+    /// become bindings instead.
+    Shortcuts,
+    /// Zooming and panning the camera. Only the game and the editor show a board, on the other
+    /// screens the camera would move behind the UI.
+    Camera,
+}
+
+/// Action mapping for every key and mouse button the game reacts to, and the screen used to
+/// rebind them.
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputMap::load());
+        app.init_resource::<RebindCapture>();
+
+        app.configure_sets(
+            Update,
+            (
+                InputSet::Shortcuts.run_if(not(in_state(AppState::Controls))),
+                InputSet::Camera
+                    .run_if(in_state(AppState::InGame).or_else(in_state(AppState::Editor))),
+            ),
+        );
+
+        app.add_systems(OnEnter(AppState::Controls), Self::spawn_controls_screen);
+        app.add_systems(
+            Update,
+            (
+                Self::handle_buttons,
+                Self::capture_binding,
+                Self::update_binding_texts,
+                Self::update_status_text,
+            )
+                .chain()
+                .run_if(in_state(AppState::Controls)),
+        );
+        app.add_systems(OnExit(AppState::Controls), Self::despawn_controls_screen);
+
+        info!("Loaded Controls Plugin");
+    }
+}
+
+impl ControlsPlugin {
+    fn spawn_controls_screen(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+    ) {
+        let font = asset_server.load(UI_FONT_PATH);
+        let button_style = Style {
+            width: Val::Px(380f32),
+            padding: UiRect::axes(Val::Px(8f32), Val::Px(4f32)),
+            justify_content: JustifyContent::SpaceBetween,
+            ..default()
+        };
+
+        commands
+            .spawn((
+                Name::new("Controls Screen"),
+                ControlsScreen,
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100f32),
+                        height: Val::Percent(100f32),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(10f32),
+                        ..default()
+                    },
+                    background_color: MENU_BACKGROUND_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    localization.text("controls-title"),
+                    text_style(&font, 48f32, Color::WHITE),
+                ));
+
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            display: Display::Grid,
+                            grid_template_columns: RepeatedGridTrack::auto(2),
+                            row_gap: Val::Px(4f32),
+                            column_gap: Val::Px(8f32),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|grid| {
+                        for action in Action::ALL {
+                            grid.spawn((
+                                RebindButton(action),
+                                ButtonBundle {
+                                    style: button_style.clone(),
+                                    background_color: BUTTON_COLOR.into(),
+                                    ..default()
+                                },
+                            ))
+                            .with_children(|button| {
+                                button.spawn(TextBundle::from_section(
+                                    localization.text(action.message_id()),
+                                    text_style(&font, 18f32, Color::WHITE),
+                                ));
+                                button.spawn((
+                                    BindingText(action),
+                                    TextBundle::from_section(
+                                        "",
+                                        text_style(&font, 18f32, Color::WHITE),
+                                    ),
+                                ));
+                            });
+                        }
+                    });
+
+                parent
+                    .spawn((
+                        ResetBindingsButton,
+                        ButtonBundle {
+                            style: Style {
+                                padding: UiRect::axes(Val::Px(16f32), Val::Px(4f32)),
+                                ..default()
+                            },
+                            background_color: BUTTON_COLOR.into(),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|button| {
+                        button.spawn(TextBundle::from_section(
+                            localization.text("controls-reset"),
+                            text_style(&font, 18f32, Color::WHITE),
+                        ));
+                    });
+
+                parent.spawn((
+                    ControlsStatusText,
+                    TextBundle::from_section("", text_style(&font, 18f32, Color::GRAY)),
+                ));
+            });
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn handle_buttons(
+        mut input_map: ResMut<InputMap>,
+        mut capture: ResMut<RebindCapture>,
+        mut buttons: Query<
+            (
+                &Interaction,
+                Option<&RebindButton>,
+                Option<&ResetBindingsButton>,
+                &mut BackgroundColor,
+            ),
+            (
+                Changed<Interaction>,
+                Or<(With<RebindButton>, With<ResetBindingsButton>)>,
+            ),
+        >,
+    ) {
+        for (interaction, rebind, reset, mut background_color) in buttons.iter_mut() {
+            let capturing = rebind.is_some_and(|RebindButton(action)| capture.0 == Some(*action));
+            match interaction {
+                // While waiting for a binding, clicks are the binding itself
+                Interaction::Pressed if capture.0.is_none() => {
+                    if let Some(RebindButton(action)) = rebind {
+                        info!("Waiting for a new binding for '{}'", action);
+                        capture.0 = Some(*action);
+                    }
+                    if reset.is_some() {
+                        info!("Restoring the default controls");
+                        input_map.reset();
+                        input_map.save();
+                    }
+                }
+                Interaction::Pressed => {}
+                _ if capturing => {}
+                Interaction::Hovered => *background_color = BUTTON_HOVERED_COLOR.into(),
+                Interaction::None => *background_color = BUTTON_COLOR.into(),
+            }
+        }
+    }
+
+    /// This system adds the next pressed key or mouse button to the action waiting for a
+    /// binding, the button bound to `Action::ClearBindings` (Backspace by default) removes all
+    /// bindings of the action instead. Escape cancels and is not rebindable, so a capture can
+    /// always be left. When no action is waiting, `Action::Back` goes back to the settings.
+    fn capture_binding(
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        mut input_map: ResMut<InputMap>,
+        mut capture: ResMut<RebindCapture>,
+        mut next_state: ResMut<NextState<AppState>>,
+    ) {
+        let Some(action) = capture.0 else {
+            if input_map.just_pressed(Action::Back, &keys, &buttons) {
+                info!(
+                    "[{}] pressed. Leaving the controls",
+                    input_map.label(Action::Back)
+                );
+                next_state.set(AppState::Settings);
+            }
+            return;
+        };
+        // The click starting the capture must not become the binding
+        if capture.is_changed() {
+            return;
+        }
+
+        if keys.just_pressed(KeyCode::Escape) {
+            info!("[Escape] key pressed. Keeping the bindings of '{}'", action);
+            capture.0 = None;
+            return;
+        }
+
+        if input_map.just_pressed(Action::ClearBindings, &keys, &buttons) {
+            info!(
+                "[{}] pressed. Removing the bindings of '{}'",
+                input_map.label(Action::ClearBindings),
+                action
+            );
+            input_map.unbind_all(action);
+            input_map.save();
+            capture.0 = None;
+            return;
+        }
+
+        let binding = keys
+            .get_just_pressed()
+            .next()
+            .map(|key| InputBinding::Key(*key))
+            .or_else(|| {
+                buttons
+                    .get_just_pressed()
+                    .next()
+                    .map(|button| InputBinding::Mouse(*button))
+            });
+        if let Some(binding) = binding {
+            info!("Binding {} to '{}'", binding, action);
+            input_map.bind(action, binding);
+            input_map.save();
+            capture.0 = None;
+        }
+    }
+
+    fn update_binding_texts(
+        input_map: Res<InputMap>,
+        capture: Res<RebindCapture>,
+        mut texts: Query<(&BindingText, &mut Text, &Parent)>,
+        mut backgrounds: Query<&mut BackgroundColor, With<RebindButton>>,
+    ) {
+        for (BindingText(action), mut text, parent) in texts.iter_mut() {
+            if !input_map.is_changed() && !capture.is_changed() && !text.is_added() {
+                continue;
+            }
+
+            let capturing = capture.0 == Some(*action);
+            text.sections[0].value = if capturing {
+                String::from("...")
+            } else {
+                input_map.label(*action)
+            };
+            text.sections[0].style.color = if input_map.has_conflict(*action) {
+                CONFLICT_TEXT_COLOR
+            } else {
+                Color::WHITE
+            };
+
+            if let Ok(mut background_color) = backgrounds.get_mut(parent.get()) {
+                if capturing {
+                    *background_color = CAPTURING_COLOR.into();
+                } else if background_color.0 == CAPTURING_COLOR {
+                    *background_color = BUTTON_COLOR.into();
+                }
+            }
+        }
+    }
+
+    fn update_status_text(
+        input_map: Res<InputMap>,
+        localization: Res<Localization>,
+        capture: Res<RebindCapture>,
+        mut texts: Query<&mut Text, With<ControlsStatusText>>,
+    ) {
+        for mut text in texts.iter_mut() {
+            if !input_map.is_changed() && !capture.is_changed() && !text.is_added() {
+                continue;
+            }
+
+            let conflicts = input_map.conflicts();
+            text.sections[0].value = if let Some(action) = capture.0 {
+                localization.text_with(
+                    "controls-capture",
+                    &[
+                        ("action", localization.text(action.message_id()).into()),
+                        ("key", input_map.label(Action::ClearBindings).into()),
+                    ],
+                )
+            } else if !conflicts.is_empty() {
+                conflicts
+                    .iter()
+                    .map(|(binding, first, second)| {
+                        localization.text_with(
+                            "controls-conflict",
+                            &[
+                                ("binding", binding.to_string().into()),
+                                ("first", localization.text(first.message_id()).into()),
+                                ("second", localization.text(second.message_id()).into()),
+                            ],
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            } else {
+                localization.text_with(
+                    "controls-hint",
+                    &[("key", input_map.label(Action::Back).into())],
+                )
+            };
+            text.sections[0].style.color = if conflicts.is_empty() || capture.0.is_some() {
+                Color::GRAY
+            } else {
+                CONFLICT_TEXT_COLOR
+            };
+        }
+    }
+
+    fn despawn_controls_screen(
+        mut commands: Commands,
+        mut capture: ResMut<RebindCapture>,
+        screens: Query<Entity, With<ControlsScreen>>,
+    ) {
+        capture.0 = None;
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub use controls_plugin::ControlsPlugin;
+pub use controls_plugin::InputSet;
+
+#[allow(clippy::module_inception)]
+mod controls_plugin;
@@ -0,0 +1,275 @@
+use bevy::prelude::*;
+
+use crate::components::TileCursorHighlight;
+use crate::plugins::{TileMarkEvent, TileTriggerEvent};
+use crate::resources::{Action, Board, GameplaySettings, InputMap, TileCursor};
+use crate::systems::{trigger_event_handler, Minesweeper2dCamera};
+use crate::AppState;
+
+/// Seconds a movement key has to be held before it starts repeating.
+const KEY_REPEAT_DELAY: f32 = 0.3;
+/// Seconds between repeated moves while a movement key is held.
+const KEY_REPEAT_INTERVAL: f32 = 0.06;
+const HIGHLIGHT_COLOR: Color = Color::rgba(1f32, 0.84, 0f32, 0.45);
+/// Drawn above covers and flags.
+const HIGHLIGHT_Z: f32 = 5f32;
+
+const DIRECTIONS: [(Action, IVec2); 4] = [
+    (Action::CursorLeft, IVec2::NEG_X),
+    (Action::CursorRight, IVec2::X),
+    (Action::CursorUp, IVec2::Y),
+    (Action::CursorDown, IVec2::NEG_Y),
+];
+
+/// Keyboard play: a tile cursor moved with the arrow keys, WASD or HJKL by default, used to
+/// uncover, flag and chord tiles.
+pub struct CursorPlugin;
+
+impl Plugin for CursorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TileCursor>();
+
+        app.add_systems(
+            Update,
+            (
+                Self::reset_cursor,
+                Self::move_cursor,
+                Self::hide_cursor_on_mouse_move,
+                Self::use_cursor.before(trigger_event_handler),
+                Self::update_highlight,
+                Self::follow_cursor,
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+
+        info!("Loaded Cursor Plugin");
+    }
+}
+
+impl CursorPlugin {
+    fn reset_cursor(board: Option<Res<Board>>, mut cursor: ResMut<TileCursor>) {
+        if let Some(board) = board.filter(|board| board.is_added()) {
+            cursor.center(&board);
+        }
+    }
+
+    /// This system moves the cursor with the keys bound to the cursor actions, repeating while
+    /// held. With Ctrl it jumps to the edge of the board. The keys bound to
+    /// `Action::NextUnresolved` (Tab by default) jump to the next unresolved tile, or the previous
+    /// one with Shift.
+    fn move_cursor(
+        input_map: Res<InputMap>,
+        input: Res<Input<KeyCode>>,
+        time: Res<Time>,
+        board: Option<Res<Board>>,
+        mut cursor: ResMut<TileCursor>,
+        mut held_for: Local<f32>,
+        mut next_repeat: Local<f32>,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        let shift = input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        let ctrl = input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+
+        if input.any_just_pressed(input_map.keys(Action::NextUnresolved)) {
+            cursor.jump_to_unresolved(&board, !shift);
+            cursor.visible = true;
+            return;
+        }
+
+        // Shift + cursor keys pan the camera
+        if shift {
+            return;
+        }
+
+        let direction = Self::held_direction(&input_map, &input);
+        if direction == IVec2::ZERO {
+            *held_for = 0f32;
+            return;
+        }
+
+        let just_pressed = DIRECTIONS
+            .iter()
+            .any(|(action, _)| input.any_just_pressed(input_map.keys(*action)));
+        if just_pressed {
+            *held_for = 0f32;
+            *next_repeat = KEY_REPEAT_DELAY;
+        } else {
+            *held_for += time.delta_seconds();
+            if *held_for < *next_repeat {
+                return;
+            }
+            *next_repeat += KEY_REPEAT_INTERVAL;
+        }
+
+        if ctrl {
+            cursor.jump_to_edge(&board, direction);
+        } else {
+            cursor.step(&board, direction);
+        }
+        cursor.visible = true;
+    }
+
+    fn held_direction(input_map: &InputMap, input: &Input<KeyCode>) -> IVec2 {
+        DIRECTIONS
+            .iter()
+            .filter(|(action, _)| input.any_pressed(input_map.keys(*action)))
+            .map(|(_, direction)| *direction)
+            .sum()
+    }
+
+    fn hide_cursor_on_mouse_move(
+        mut cursor: ResMut<TileCursor>,
+        mut cursor_moved_event_reader: EventReader<CursorMoved>,
+    ) {
+        if cursor_moved_event_reader.read().count() > 0 && cursor.visible {
+            cursor.visible = false;
+        }
+    }
+
+    /// This system uncovers the tile under the cursor (or chords on a number), chords and flags
+    /// it with the keys bound to `Action::Uncover`, `Action::Chord` and `Action::Flag` (Space,
+    /// Enter and F by default). Chording follows `GameplaySettings::chord_mode`.
+    fn use_cursor(
+        input_map: Res<InputMap>,
+        input: Res<Input<KeyCode>>,
+        board: Option<Res<Board>>,
+        gameplay: Res<GameplaySettings>,
+        mut cursor: ResMut<TileCursor>,
+        mut tile_trigger_event_writer: EventWriter<TileTriggerEvent>,
+        mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        let coordinates = cursor.coordinates;
+
+        if input.any_just_pressed(input_map.keys(Action::Uncover)) {
+            info!(
+                "[{}] pressed. Trying to uncover tile on {}",
+                input_map.label(Action::Uncover),
+                coordinates
+            );
+            if board.covered_tiles.contains_key(&coordinates) || gameplay.allows_chord(false) {
+                tile_trigger_event_writer.send(TileTriggerEvent { coordinates });
+            } else {
+                info!("Chording by uncovering a number is disabled");
+            }
+            cursor.visible = true;
+        }
+
+        if input.any_just_pressed(input_map.keys(Action::Chord)) {
+            info!(
+                "[{}] pressed. Trying to chord on {}",
+                input_map.label(Action::Chord),
+                coordinates
+            );
+            if board.covered_tiles.contains_key(&coordinates) {
+                warn!("Only uncovered numbers can be chorded");
+            } else if !gameplay.allows_chord(true) {
+                info!("Chording is disabled");
+            } else {
+                tile_trigger_event_writer.send(TileTriggerEvent { coordinates });
+            }
+            cursor.visible = true;
+        }
+
+        if input.any_just_pressed(input_map.keys(Action::Flag)) {
+            info!(
+                "[{}] pressed. Trying to mark tile on {}",
+                input_map.label(Action::Flag),
+                coordinates
+            );
+            tile_mark_event_writer.send(TileMarkEvent(coordinates));
+            cursor.visible = true;
+        }
+    }
+
+    /// Keeps the highlight on the cursor tile. It is a child of the board, so it is despawned
+    /// with it and follows relayouts.
+    fn update_highlight(
+        mut commands: Commands,
+        board: Option<Res<Board>>,
+        cursor: Res<TileCursor>,
+        mut highlights: Query<
+            (&mut Transform, &mut Sprite, &mut Visibility),
+            With<TileCursorHighlight>,
+        >,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+
+        let position = Vec2::new(
+            cursor.coordinates.x as f32 + 0.5,
+            cursor.coordinates.y as f32 + 0.5,
+        ) * board.tile_size;
+        let size = Vec2::splat(board.tile_size);
+        let visibility = if cursor.visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        match highlights.get_single_mut() {
+            Ok((mut transform, mut sprite, mut highlight_visibility)) => {
+                if cursor.is_changed() || board.is_changed() {
+                    transform.translation = position.extend(HIGHLIGHT_Z);
+                    sprite.custom_size = Some(size);
+                    *highlight_visibility = visibility;
+                }
+            }
+            Err(_) => {
+                commands.entity(board.entity).with_children(|parent| {
+                    parent.spawn((
+                        Name::new("Tile Cursor"),
+                        TileCursorHighlight,
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: HIGHLIGHT_COLOR,
+                                custom_size: Some(size),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position.extend(HIGHLIGHT_Z)),
+                            visibility,
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        }
+    }
+
+    /// Pans the camera when the cursor leaves the visible part of the board.
+    fn follow_cursor(
+        board: Option<Res<Board>>,
+        cursor: Res<TileCursor>,
+        mut camera_query: Query<
+            (&Camera, &mut Transform, &OrthographicProjection),
+            With<Minesweeper2dCamera>,
+        >,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        if !cursor.is_changed() || !cursor.visible {
+            return;
+        }
+
+        let tile = board.tile_center(cursor.coordinates);
+        for (camera, mut transform, projection) in camera_query.iter_mut() {
+            let Some(viewport) = camera.logical_viewport_size() else {
+                continue;
+            };
+            let half_view = viewport * projection.scale / 2f32;
+            // Keep the whole tile in view
+            let half_extent = (half_view - board.tile_size).max(Vec2::ZERO);
+            let center = transform.translation.truncate();
+            let offset = tile - center;
+            let correction = offset - offset.clamp(-half_extent, half_extent);
+            transform.translation += correction.extend(0f32);
+        }
+    }
+}
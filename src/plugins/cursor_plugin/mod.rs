@@ -0,0 +1,4 @@
+pub use cursor_plugin::CursorPlugin;
+
+#[allow(clippy::module_inception)]
+mod cursor_plugin;
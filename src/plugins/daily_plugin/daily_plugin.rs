@@ -0,0 +1,317 @@
+use bevy::prelude::*;
+use chrono::Datelike;
+
+use crate::components::DailyScreen;
+use crate::plugins::{BoardCompletedEvent, MineExplosionEvent};
+use crate::resources::{
+    Action, ActivePuzzle, Board, BoardLayout, DailyCalendar, DailyChallenge, DailyHistory,
+    DailyResult, GameTimer, InputMap, Localization,
+};
+use crate::systems::{text_style, MENU_BACKGROUND_COLOR, UI_FONT_PATH};
+use crate::AppState;
+
+const DAY_COLOR: Color = Color::rgb(0.2, 0.2, 0.2);
+const TODAY_COLOR: Color = Color::rgb(0.3, 0.3, 0.45);
+const WON_COLOR: Color = Color::GREEN;
+const LOST_COLOR: Color = Color::RED;
+
+pub struct DailyPlugin;
+
+impl Plugin for DailyPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DailyHistory::load());
+        app.insert_resource(DailyCalendar::current());
+
+        app.add_systems(OnEnter(AppState::Daily), Self::reset_calendar);
+        app.add_systems(
+            Update,
+            (Self::handle_calendar_input, Self::spawn_calendar)
+                .chain()
+                .run_if(in_state(AppState::Daily)),
+        );
+        app.add_systems(OnExit(AppState::Daily), Self::despawn_calendar);
+
+        app.add_systems(
+            Update,
+            (Self::follow_board, Self::record_result)
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+
+        info!("Loaded Daily Plugin");
+    }
+}
+
+impl DailyPlugin {
+    fn reset_calendar(mut calendar: ResMut<DailyCalendar>) {
+        *calendar = DailyCalendar::current();
+    }
+
+    fn handle_calendar_input(
+        mut commands: Commands,
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        mut calendar: ResMut<DailyCalendar>,
+        mut history: ResMut<DailyHistory>,
+        mut next_state: ResMut<NextState<AppState>>,
+    ) {
+        if input_map.just_pressed(Action::PreviousMonth, &keys, &buttons) {
+            calendar.previous();
+        }
+
+        if input_map.just_pressed(Action::NextMonth, &keys, &buttons) {
+            calendar.next();
+        }
+
+        if input_map.just_pressed(Action::StartDaily, &keys, &buttons) {
+            let challenge = DailyChallenge::today();
+            if history.is_attempted(&challenge.date) {
+                warn!(
+                    "Daily challenge for {} was already attempted",
+                    challenge.date
+                );
+            } else {
+                info!(
+                    "[{}] pressed. Starting daily challenge for {}",
+                    input_map.label(Action::StartDaily),
+                    challenge.date
+                );
+                // Recorded right away, so restarting the game does not grant another attempt
+                history.record(challenge.date, DailyResult::Unfinished);
+                commands.remove_resource::<ActivePuzzle>();
+                commands.insert_resource(challenge.layout());
+                commands.insert_resource(challenge);
+                next_state.set(AppState::InGame);
+            }
+        }
+
+        if input_map.just_pressed(Action::Back, &keys, &buttons) {
+            info!(
+                "[{}] pressed. Leaving the daily challenge calendar",
+                input_map.label(Action::Back)
+            );
+            next_state.set(AppState::MainMenu);
+        }
+    }
+
+    /// Rebuilds the calendar whenever the displayed month changes.
+    fn spawn_calendar(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+        input_map: Res<InputMap>,
+        calendar: Res<DailyCalendar>,
+        history: Res<DailyHistory>,
+        screens: Query<Entity, With<DailyScreen>>,
+    ) {
+        if !calendar.is_changed() && !screens.is_empty() {
+            return;
+        }
+
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+
+        let font: Handle<Font> = asset_server.load(UI_FONT_PATH);
+        let today = chrono::Local::now().date_naive();
+        let footer = if history.is_attempted(&today) {
+            localization.text("daily-done")
+        } else {
+            localization.text_with(
+                "daily-play",
+                &[("key", input_map.label(Action::StartDaily).into())],
+            )
+        };
+
+        commands
+            .spawn((
+                Name::new("Daily Screen"),
+                DailyScreen,
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100f32),
+                        height: Val::Percent(100f32),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(10f32),
+                        ..default()
+                    },
+                    background_color: MENU_BACKGROUND_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    localization.text_with(
+                        "daily-title",
+                        &[
+                            (
+                                "month",
+                                localization
+                                    .text(&format!("month-{}", calendar.month.month()))
+                                    .into(),
+                            ),
+                            ("year", calendar.month.year().to_string().into()),
+                        ],
+                    ),
+                    text_style(&font, 36f32, Color::WHITE),
+                ));
+
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            display: Display::Grid,
+                            grid_template_columns: RepeatedGridTrack::px(7, 90f32),
+                            grid_auto_rows: vec![GridTrack::px(60f32)],
+                            row_gap: Val::Px(4f32),
+                            column_gap: Val::Px(4f32),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|grid| {
+                        for weekday in 1..=7 {
+                            grid.spawn(TextBundle::from_section(
+                                localization.text(&format!("weekday-{}", weekday)),
+                                text_style(&font, 20f32, Color::GRAY),
+                            ));
+                        }
+
+                        let first_column = calendar.month.weekday().num_days_from_monday() as i16;
+                        for day in calendar.days() {
+                            let (result, color) = match history.result(&day) {
+                                Some(DailyResult::Won { seconds }) => {
+                                    (Self::format_time(seconds), WON_COLOR)
+                                }
+                                Some(DailyResult::Lost) => {
+                                    (localization.text("daily-lost"), LOST_COLOR)
+                                }
+                                Some(DailyResult::Unfinished) => ("-".to_string(), Color::GRAY),
+                                None => (String::new(), Color::GRAY),
+                            };
+
+                            let column = if day.day() == 1 {
+                                GridPlacement::start(first_column + 1)
+                            } else {
+                                GridPlacement::auto()
+                            };
+
+                            grid.spawn(NodeBundle {
+                                style: Style {
+                                    grid_column: column,
+                                    flex_direction: FlexDirection::Column,
+                                    padding: UiRect::all(Val::Px(4f32)),
+                                    ..default()
+                                },
+                                background_color: if day == today {
+                                    TODAY_COLOR.into()
+                                } else {
+                                    DAY_COLOR.into()
+                                },
+                                ..default()
+                            })
+                            .with_children(|cell| {
+                                cell.spawn(TextBundle::from_section(
+                                    day.day().to_string(),
+                                    text_style(&font, 18f32, Color::WHITE),
+                                ));
+                                cell.spawn(TextBundle::from_section(
+                                    result,
+                                    text_style(&font, 18f32, color),
+                                ));
+                            });
+                        }
+                    });
+
+                parent.spawn(TextBundle::from_section(
+                    footer,
+                    text_style(&font, 20f32, Color::WHITE),
+                ));
+                parent.spawn(TextBundle::from_section(
+                    localization.text_with(
+                        "daily-hint",
+                        &[
+                            ("previous", input_map.label(Action::PreviousMonth).into()),
+                            ("next", input_map.label(Action::NextMonth).into()),
+                            ("back", input_map.label(Action::Back).into()),
+                        ],
+                    ),
+                    text_style(&font, 18f32, Color::GRAY),
+                ));
+            });
+    }
+
+    fn despawn_calendar(mut commands: Commands, screens: Query<Entity, With<DailyScreen>>) {
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    fn record_result(
+        challenge: Option<Res<DailyChallenge>>,
+        game_timer: Option<Res<GameTimer>>,
+        mut history: ResMut<DailyHistory>,
+        mut board_completed_event_reader: EventReader<BoardCompletedEvent>,
+        mut mine_explosion_event_reader: EventReader<MineExplosionEvent>,
+    ) {
+        let completed = board_completed_event_reader.read().count() > 0;
+        let exploded = mine_explosion_event_reader.read().count() > 0;
+
+        let Some(challenge) = challenge else {
+            return;
+        };
+        if history.result(&challenge.date) != Some(DailyResult::Unfinished) {
+            return;
+        }
+
+        if exploded {
+            info!("Daily challenge for {} lost", challenge.date);
+            history.record(challenge.date, DailyResult::Lost);
+        } else if completed {
+            let seconds = game_timer.map_or(0f32, |timer| timer.elapsed().as_secs_f32());
+            info!(
+                "Daily challenge for {} won in {}",
+                challenge.date,
+                Self::format_time(seconds)
+            );
+            history.record(challenge.date, DailyResult::Won { seconds });
+        }
+    }
+
+    /// The daily challenge lasts as long as its board. Its layout is dropped once the board is
+    /// generated, so regenerating the board starts a regular game, and the challenge ends when
+    /// another board replaces it. Pausing keeps the board, and so the challenge.
+    fn follow_board(
+        mut commands: Commands,
+        board: Option<Res<Board>>,
+        challenge: Option<Res<DailyChallenge>>,
+        mut challenge_board: Local<Option<Entity>>,
+    ) {
+        let (Some(board), Some(challenge)) = (board, challenge) else {
+            return;
+        };
+        if challenge.is_added() {
+            *challenge_board = None;
+        }
+
+        match *challenge_board {
+            None => {
+                *challenge_board = Some(board.entity);
+                commands.remove_resource::<BoardLayout>();
+            }
+            Some(entity) if entity != board.entity => {
+                info!("Daily challenge for {} ended", challenge.date);
+                *challenge_board = None;
+                commands.remove_resource::<DailyChallenge>();
+            }
+            Some(_) => {}
+        }
+    }
+
+    fn format_time(seconds: f32) -> String {
+        let seconds = seconds as u32;
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
@@ -0,0 +1,4 @@
+pub use daily_plugin::DailyPlugin;
+
+#[allow(clippy::module_inception)]
+mod daily_plugin;
@@ -0,0 +1,311 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::components::{BoardElement, EditorScreen, EditorStatusText};
+use crate::plugins::BoardPlugin;
+use crate::resources::{
+    Action, Board, BoardAssets, BoardMask, BoardOptions, EditorBoard, InputMap, Localization,
+    PuzzleLevel, PuzzleLevelFile, PuzzlePack,
+};
+use crate::systems::{text_style, Minesweeper2dCamera, UI_FONT_PATH};
+use crate::AppState;
+
+/// Covers are drawn translucent so mines and numbers stay visible while editing.
+const COVER_ALPHA: f32 = 0.6;
+
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::Editor),
+            (Self::enter_editor, Self::spawn_editor_screen),
+        );
+        app.add_systems(
+            Update,
+            (
+                Self::handle_mouse_input,
+                Self::handle_keyboard_input,
+                Self::redraw_board,
+                Self::update_status_text,
+            )
+                .chain()
+                .run_if(in_state(AppState::Editor)),
+        );
+        app.add_systems(OnExit(AppState::Editor), Self::exit_editor);
+
+        info!("Loaded Editor Plugin");
+    }
+}
+
+impl EditorPlugin {
+    fn enter_editor(
+        mut commands: Commands,
+        editor_board: Option<Res<EditorBoard>>,
+        board_options: Option<Res<BoardOptions>>,
+        board_mask: Option<Res<BoardMask>>,
+    ) {
+        info!("Entering the board editor");
+        // Without a `Board` the preview gets spawned on the first update
+        commands.remove_resource::<Board>();
+
+        if editor_board.is_none() {
+            let mask = match board_mask {
+                Some(mask) => mask.clone(),
+                None => {
+                    let (width, height) = board_options.map(|o| o.map_size).unwrap_or_default();
+                    BoardMask::rectangle(width, height)
+                }
+            };
+            commands.insert_resource(EditorBoard::new(mask));
+        }
+    }
+
+    fn spawn_editor_screen(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+        input_map: Res<InputMap>,
+    ) {
+        let font: Handle<Font> = asset_server.load(UI_FONT_PATH);
+
+        commands
+            .spawn((
+                Name::new("Editor Screen"),
+                EditorScreen,
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100f32),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(8f32)),
+                        ..default()
+                    },
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    localization.text_with(
+                        "editor-help",
+                        &[
+                            ("mine", input_map.label(Action::ToggleMine).into()),
+                            ("cover", input_map.label(Action::ToggleCover).into()),
+                            ("save", input_map.label(Action::SaveLevel).into()),
+                            ("clear", input_map.label(Action::ClearLevel).into()),
+                            ("back", input_map.label(Action::Back).into()),
+                        ],
+                    ),
+                    text_style(&font, 18f32, Color::WHITE),
+                ));
+                parent.spawn((
+                    EditorStatusText,
+                    TextBundle::from_section("", text_style(&font, 18f32, Color::WHITE)),
+                ));
+            });
+    }
+
+    /// This system edits the tile under the cursor when pressing the buttons bound to
+    /// `Action::ToggleMine` (LMB by default) or `Action::ToggleCover` (RMB by default).
+    fn handle_mouse_input(
+        window_query: Query<&Window, With<PrimaryWindow>>,
+        camera_query: Query<(&Camera, &GlobalTransform), With<Minesweeper2dCamera>>,
+        board: Option<Res<Board>>,
+        mut editor_board: ResMut<EditorBoard>,
+        (input_map, keys, buttons): (Res<InputMap>, Res<Input<KeyCode>>, Res<Input<MouseButton>>),
+    ) {
+        let window = window_query.single();
+        let (camera, camera_transform) = camera_query.single();
+        let Some(board) = board else {
+            return;
+        };
+
+        let toggle_mine = input_map.just_pressed(Action::ToggleMine, &keys, &buttons);
+        let toggle_cover = input_map.just_pressed(Action::ToggleCover, &keys, &buttons);
+        if !toggle_mine && !toggle_cover {
+            return;
+        }
+
+        let Some(coordinates) = window
+            .cursor_position()
+            .and_then(|position| board.mouse_position(camera, camera_transform, position))
+        else {
+            return;
+        };
+
+        if toggle_mine {
+            editor_board.toggle_mine(coordinates);
+        }
+        if toggle_cover {
+            editor_board.toggle_initial_state(coordinates);
+        }
+    }
+
+    fn handle_keyboard_input(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        mut editor_board: ResMut<EditorBoard>,
+        mut next_state: ResMut<NextState<AppState>>,
+    ) {
+        if input_map.just_pressed(Action::ClearLevel, &keys, &buttons) {
+            info!(
+                "[{}] pressed. Clearing the editor board",
+                input_map.label(Action::ClearLevel)
+            );
+            editor_board.clear();
+        }
+
+        if input_map.just_pressed(Action::SaveLevel, &keys, &buttons) {
+            info!(
+                "[{}] pressed. Saving the editor board",
+                input_map.label(Action::SaveLevel)
+            );
+            Self::save_level(&editor_board);
+        }
+
+        if input_map.just_pressed(Action::Back, &keys, &buttons) {
+            info!(
+                "[{}] pressed. Leaving the board editor",
+                input_map.label(Action::Back)
+            );
+            next_state.set(AppState::MainMenu);
+        }
+    }
+
+    fn save_level(editor_board: &EditorBoard) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let directory = PuzzlePack::user_directory();
+        // Numbered when several levels are saved within the same second
+        let (suffix, path) = (1..)
+            .map(|index| match index {
+                1 => timestamp.to_string(),
+                _ => format!("{}-{}", timestamp, index),
+            })
+            .map(|suffix| {
+                let path = directory.join(format!("custom-{}.ron", suffix));
+                (suffix, path)
+            })
+            .find(|(_, path)| !path.exists())
+            .unwrap();
+        let id = format!("custom-{}", suffix);
+        let file =
+            PuzzleLevelFile::from_layout(format!("Custom {}", suffix), &editor_board.layout());
+
+        // Parsing the file back runs the same validation as loading a puzzle pack
+        if let Err(e) = PuzzleLevel::parse(id.clone(), file.clone()) {
+            warn!("Level was not saved: {}", e);
+            return;
+        }
+
+        let result = std::fs::create_dir_all(&directory)
+            .map_err(Into::into)
+            .and_then(|_| file.save(&path));
+        match result {
+            Ok(()) => info!("Level saved to {}", path.display()),
+            Err(e) => error!("Failed to save level to {}: {}", path.display(), e),
+        }
+    }
+
+    /// Respawns the preview board whenever the edited board or the board assets change, so mine
+    /// counters are always up to date.
+    fn redraw_board(
+        mut commands: Commands,
+        editor_board: Res<EditorBoard>,
+        board: Option<Res<Board>>,
+        board_options: Option<Res<BoardOptions>>,
+        board_assets: Res<BoardAssets>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        if board.is_some() && !editor_board.is_changed() && !board_assets.is_changed() {
+            return;
+        }
+
+        if let Some(board) = board {
+            commands.entity(board.entity).despawn_recursive();
+        }
+
+        let options = board_options.map(|o| *o).unwrap_or_default();
+        let mut assets = board_assets.clone();
+        assets.covered_tile_material.color.set_a(COVER_ALPHA);
+
+        let (board, _) = BoardPlugin::<AppState>::spawn_board(
+            &mut commands,
+            editor_board.tile_map(),
+            &options,
+            window_query.single(),
+            &assets,
+        );
+
+        for (coordinates, cover) in board.covered_tiles.iter() {
+            if editor_board.is_revealed(coordinates) {
+                commands.entity(*cover).despawn_recursive();
+            } else if editor_board.is_flagged(coordinates) {
+                commands.entity(*cover).with_children(|parent| {
+                    parent.spawn((
+                        Name::new("Flag"),
+                        BoardElement::Flag,
+                        SpriteBundle {
+                            texture: assets.flag_material.texture.clone(),
+                            sprite: Sprite {
+                                custom_size: Some(Vec2::splat(board.tile_size)),
+                                color: assets.flag_material.color,
+                                ..default()
+                            },
+                            transform: Transform::from_xyz(0f32, 0f32, 1f32),
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        }
+
+        commands.insert_resource(board);
+    }
+
+    fn update_status_text(
+        editor_board: Res<EditorBoard>,
+        localization: Res<Localization>,
+        mut texts: Query<&mut Text, With<EditorStatusText>>,
+    ) {
+        for mut text in texts.iter_mut() {
+            if !editor_board.is_changed() && !text.is_added() {
+                continue;
+            }
+
+            let solvable = if editor_board.is_solvable() {
+                "yes"
+            } else {
+                "no"
+            };
+            text.sections[0].value = localization.text_with(
+                "editor-status",
+                &[
+                    ("mines", editor_board.mine_count().into()),
+                    ("revealed", editor_board.revealed_count().into()),
+                    ("solvable", solvable.into()),
+                ],
+            );
+        }
+    }
+
+    fn exit_editor(
+        mut commands: Commands,
+        board: Option<Res<Board>>,
+        screens: Query<Entity, With<EditorScreen>>,
+    ) {
+        if let Some(board) = board {
+            commands.entity(board.entity).despawn_recursive();
+        }
+        commands.remove_resource::<Board>();
+
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
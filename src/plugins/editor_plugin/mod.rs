@@ -0,0 +1,4 @@
+pub use editor_plugin::EditorPlugin;
+
+#[allow(clippy::module_inception)]
+mod editor_plugin;
@@ -0,0 +1,230 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use rand::Rng;
+
+use crate::components::{CameraShake, Particle};
+use crate::plugins::{BoardCompletedEvent, MineExplosionEvent};
+use crate::resources::{AnimationSettings, Board, BoardAssets};
+use crate::systems::Minesweeper2dCamera;
+
+const DEBRIS_COUNT: f32 = 40f32;
+const SMOKE_COUNT: f32 = 12f32;
+const CONFETTI_COUNT: f32 = 150f32;
+const CAMERA_SHAKE_DURATION: f32 = 0.4;
+/// Particles are drawn above every board element.
+const PARTICLE_Z: f32 = 10f32;
+
+/// Debris, smoke and camera shake when a mine explodes and confetti when the board is completed.
+/// Colors and strength come from the theme, the effects are skipped when animations are off.
+pub struct EffectsPlugin;
+
+impl Plugin for EffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                Self::spawn_explosion,
+                Self::spawn_confetti,
+                Self::update_particles,
+            ),
+        );
+        // After every system zooming, panning or fitting the camera in `Update`, so the offset
+        // removed on the next frame is the one applied here
+        app.add_systems(
+            PostUpdate,
+            Self::shake_camera.before(TransformSystem::TransformPropagate),
+        );
+
+        info!("Loaded Effects Plugin");
+    }
+}
+
+impl EffectsPlugin {
+    fn spawn_explosion(
+        mut commands: Commands,
+        board: Option<Res<Board>>,
+        board_assets: Res<BoardAssets>,
+        settings: Res<AnimationSettings>,
+        mut camera_query: Query<(Entity, Option<&mut CameraShake>), With<Minesweeper2dCamera>>,
+        mut mine_explosion_event_reader: EventReader<MineExplosionEvent>,
+    ) {
+        let Some(board) = board else {
+            mine_explosion_event_reader.clear();
+            return;
+        };
+
+        for event in mine_explosion_event_reader.read() {
+            if !settings.is_enabled() {
+                continue;
+            }
+
+            let effects = &board_assets.effects;
+            let origin = board.tile_center(event.coordinates);
+            let mut rng = rand::thread_rng();
+
+            if !effects.debris_colors.is_empty() {
+                for _ in 0..(DEBRIS_COUNT * effects.particle_density) as usize {
+                    let angle = rng.gen_range(0f32..TAU);
+                    let speed = rng.gen_range(4f32..12f32) * board.tile_size;
+                    let color =
+                        effects.debris_colors[rng.gen_range(0..effects.debris_colors.len())];
+                    let size = board.tile_size * rng.gen_range(0.1..0.25);
+                    let particle = Particle {
+                        gravity: 20f32 * board.tile_size,
+                        drag: 1.5,
+                        spin: rng.gen_range(-10f32..10f32),
+                        ..Particle::new(Vec2::from_angle(angle) * speed, rng.gen_range(0.6..1.2))
+                    };
+                    Self::spawn_particle(&mut commands, particle, origin, Vec2::splat(size), color);
+                }
+            }
+
+            for _ in 0..(SMOKE_COUNT * effects.particle_density) as usize {
+                let velocity = Vec2::new(rng.gen_range(-1f32..1f32), rng.gen_range(0.5..2f32))
+                    * board.tile_size;
+                let size = board.tile_size * rng.gen_range(0.6..1f32);
+                let particle = Particle {
+                    gravity: -board.tile_size,
+                    drag: 0.8,
+                    end_scale: 2.5,
+                    alpha: effects.smoke_color.a(),
+                    ..Particle::new(velocity, rng.gen_range(1f32..1.8))
+                };
+                Self::spawn_particle(
+                    &mut commands,
+                    particle,
+                    origin,
+                    Vec2::splat(size),
+                    effects.smoke_color,
+                );
+            }
+
+            if effects.camera_shake > 0f32 {
+                for (camera, shake) in camera_query.iter_mut() {
+                    match shake {
+                        // Restart a running shake, keeping its offset so it can be undone
+                        Some(mut shake) => shake.remaining = shake.duration,
+                        None => {
+                            commands.entity(camera).insert(CameraShake::new(
+                                effects.camera_shake,
+                                CAMERA_SHAKE_DURATION,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn spawn_confetti(
+        mut commands: Commands,
+        board: Option<Res<Board>>,
+        board_assets: Res<BoardAssets>,
+        settings: Res<AnimationSettings>,
+        mut board_completed_event_reader: EventReader<BoardCompletedEvent>,
+    ) {
+        let completed = board_completed_event_reader.read().count() > 0;
+        let Some(board) = board else {
+            return;
+        };
+        let effects = &board_assets.effects;
+        if !completed || !settings.is_enabled() || effects.confetti_colors.is_empty() {
+            return;
+        }
+
+        info!("Board completed, throwing confetti");
+        let mut rng = rand::thread_rng();
+        let bounds = board.bounds;
+        for _ in 0..(CONFETTI_COUNT * effects.particle_density) as usize {
+            let position = Vec2::new(
+                bounds.position.x + rng.gen_range(0f32..=bounds.size.x),
+                bounds.position.y + bounds.size.y + rng.gen_range(0f32..=bounds.size.y * 0.3),
+            );
+            let velocity = Vec2::new(rng.gen_range(-2f32..2f32), rng.gen_range(-5f32..-1f32))
+                * board.tile_size;
+            let size = Vec2::new(0.2, 0.35) * board.tile_size;
+            let color = effects.confetti_colors[rng.gen_range(0..effects.confetti_colors.len())];
+            let particle = Particle {
+                gravity: 4f32 * board.tile_size,
+                drag: 0.5,
+                spin: rng.gen_range(-8f32..8f32),
+                ..Particle::new(velocity, rng.gen_range(2.5..4f32))
+            };
+            Self::spawn_particle(&mut commands, particle, position, size, color);
+        }
+    }
+
+    fn spawn_particle(
+        commands: &mut Commands,
+        particle: Particle,
+        position: Vec2,
+        size: Vec2,
+        color: Color,
+    ) {
+        commands.spawn((
+            Name::new("Particle"),
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(size),
+                    ..default()
+                },
+                transform: Transform::from_translation(position.extend(PARTICLE_Z)),
+                ..default()
+            },
+            particle,
+        ));
+    }
+
+    fn update_particles(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut particles: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+    ) {
+        let delta = time.delta_seconds();
+
+        for (entity, mut particle, mut transform, mut sprite) in particles.iter_mut() {
+            particle.age += delta;
+            if particle.age >= particle.lifetime {
+                commands.entity(entity).despawn();
+                continue;
+            }
+
+            particle.velocity.y -= particle.gravity * delta;
+            let drag = (1f32 - particle.drag * delta).max(0f32);
+            particle.velocity *= drag;
+
+            transform.translation += (particle.velocity * delta).extend(0f32);
+            transform.rotate_z(particle.spin * delta);
+
+            let progress = particle.age / particle.lifetime;
+            transform.scale = Vec3::splat(1f32 + (particle.end_scale - 1f32) * progress);
+            sprite.color.set_a(particle.alpha * (1f32 - progress));
+        }
+    }
+
+    fn shake_camera(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut cameras: Query<(Entity, &mut CameraShake, &mut Transform)>,
+    ) {
+        let mut rng = rand::thread_rng();
+
+        for (entity, mut shake, mut transform) in cameras.iter_mut() {
+            transform.translation -= shake.offset.extend(0f32);
+            shake.remaining -= time.delta_seconds();
+
+            if shake.remaining <= 0f32 {
+                commands.entity(entity).remove::<CameraShake>();
+                continue;
+            }
+
+            let strength = (shake.remaining / shake.duration).powi(2) * shake.intensity;
+            shake.offset =
+                Vec2::new(rng.gen_range(-1f32..1f32), rng.gen_range(-1f32..1f32)) * strength;
+            transform.translation += shake.offset.extend(0f32);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use effects_plugin::EffectsPlugin;
+
+#[allow(clippy::module_inception)]
+mod effects_plugin;
@@ -0,0 +1,376 @@
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+use bevy::prelude::*;
+
+use crate::components::GamepadHud;
+use crate::plugins::{CameraPlugin, InputSet, TileMarkEvent, TileTriggerEvent};
+use crate::resources::{Board, GameplaySettings, Localization, TileCursor};
+use crate::systems::{
+    text_style, trigger_event_handler, Minesweeper2dCamera, OVERLAY_COLOR, UI_FONT_PATH,
+};
+use crate::AppState;
+
+/// Stick deflection counted as a direction, the game reacts to whole tiles so it is high.
+const STICK_THRESHOLD: f32 = 0.5;
+/// Stick deflection below which the right stick does not pan.
+const PAN_DEADZONE: f32 = 0.2;
+/// Seconds a direction has to be held before the cursor starts repeating.
+const REPEAT_DELAY: f32 = 0.3;
+/// Seconds between repeated moves while a direction is held.
+const REPEAT_INTERVAL: f32 = 0.08;
+/// Zoom steps per second while a shoulder button is held.
+const ZOOM_SPEED: f32 = 6f32;
+
+const UNCOVER_BUTTON: GamepadButtonType = GamepadButtonType::South;
+const FLAG_BUTTON: GamepadButtonType = GamepadButtonType::East;
+const CHORD_BUTTON: GamepadButtonType = GamepadButtonType::West;
+const ZOOM_IN_BUTTON: GamepadButtonType = GamepadButtonType::RightTrigger;
+const ZOOM_OUT_BUTTON: GamepadButtonType = GamepadButtonType::LeftTrigger;
+const MENU_BUTTON: GamepadButtonType = GamepadButtonType::Start;
+
+const HUD_GLYPH_SIZE: f32 = 24f32;
+/// Button glyphs shown in the HUD: label, glyph colour and the message describing what the
+/// button does.
+const HUD_GLYPHS: [(&str, Color, &str); 7] = [
+    ("A", Color::rgb(0.33, 0.73, 0.25), "gamepad-uncover"),
+    ("B", Color::rgb(0.85, 0.2, 0.2), "gamepad-flag"),
+    ("X", Color::rgb(0.2, 0.45, 0.85), "gamepad-chord"),
+    ("LS", Color::rgb(0.4, 0.4, 0.4), "gamepad-move"),
+    ("RS", Color::rgb(0.4, 0.4, 0.4), "gamepad-pan"),
+    ("LB RB", Color::rgb(0.4, 0.4, 0.4), "gamepad-zoom"),
+    ("Start", Color::rgb(0.4, 0.4, 0.4), "gamepad-menu"),
+];
+
+/// Cursor direction held on a gamepad, for key repeat.
+#[derive(Default)]
+struct HeldDirection {
+    direction: IVec2,
+    held_for: f32,
+    next_repeat: f32,
+}
+
+/// Controller play: the left stick or d-pad moves the tile cursor, face buttons uncover, flag
+/// and chord, the right stick pans and the shoulder buttons zoom. Gamepads can be connected
+/// and disconnected at any time.
+pub struct GamepadPlugin;
+
+impl Plugin for GamepadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                Self::log_connections,
+                Self::open_menu,
+                Self::zoom_and_pan.in_set(InputSet::Camera),
+                Self::update_hud,
+            ),
+        );
+        app.add_systems(
+            Update,
+            (
+                Self::move_cursor,
+                Self::use_cursor.before(trigger_event_handler),
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+
+        info!("Loaded Gamepad Plugin");
+    }
+}
+
+impl GamepadPlugin {
+    fn log_connections(mut connection_event_reader: EventReader<GamepadConnectionEvent>) {
+        for event in connection_event_reader.read() {
+            match &event.connection {
+                GamepadConnection::Connected(info) => {
+                    info!("Gamepad {} connected: {}", event.gamepad.id, info.name)
+                }
+                GamepadConnection::Disconnected => {
+                    info!("Gamepad {} disconnected", event.gamepad.id)
+                }
+            }
+        }
+    }
+
+    /// This system moves the cursor with the left stick or the d-pad of any connected gamepad,
+    /// repeating while held.
+    fn move_cursor(
+        gamepads: Res<Gamepads>,
+        buttons: Res<Input<GamepadButton>>,
+        axes: Res<Axis<GamepadAxis>>,
+        time: Res<Time>,
+        board: Option<Res<Board>>,
+        mut cursor: ResMut<TileCursor>,
+        mut held: Local<HeldDirection>,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+
+        let direction = gamepads
+            .iter()
+            .map(|gamepad| Self::direction(gamepad, &buttons, &axes))
+            .find(|direction| *direction != IVec2::ZERO)
+            .unwrap_or_default();
+        if direction == IVec2::ZERO {
+            held.direction = IVec2::ZERO;
+            return;
+        }
+
+        if direction != held.direction {
+            *held = HeldDirection {
+                direction,
+                held_for: 0f32,
+                next_repeat: REPEAT_DELAY,
+            };
+        } else {
+            held.held_for += time.delta_seconds();
+            if held.held_for < held.next_repeat {
+                return;
+            }
+            held.next_repeat += REPEAT_INTERVAL;
+        }
+
+        cursor.step(&board, direction);
+        cursor.visible = true;
+    }
+
+    fn direction(
+        gamepad: Gamepad,
+        buttons: &Input<GamepadButton>,
+        axes: &Axis<GamepadAxis>,
+    ) -> IVec2 {
+        let pressed = |button_type| buttons.pressed(GamepadButton::new(gamepad, button_type));
+        let axis = |axis_type| {
+            axes.get(GamepadAxis::new(gamepad, axis_type))
+                .unwrap_or_default()
+        };
+
+        let mut direction = IVec2::ZERO;
+        if pressed(GamepadButtonType::DPadLeft)
+            || axis(GamepadAxisType::LeftStickX) < -STICK_THRESHOLD
+        {
+            direction.x -= 1;
+        }
+        if pressed(GamepadButtonType::DPadRight)
+            || axis(GamepadAxisType::LeftStickX) > STICK_THRESHOLD
+        {
+            direction.x += 1;
+        }
+        if pressed(GamepadButtonType::DPadUp) || axis(GamepadAxisType::LeftStickY) > STICK_THRESHOLD
+        {
+            direction.y += 1;
+        }
+        if pressed(GamepadButtonType::DPadDown)
+            || axis(GamepadAxisType::LeftStickY) < -STICK_THRESHOLD
+        {
+            direction.y -= 1;
+        }
+        direction
+    }
+
+    /// This system uncovers the tile under the cursor (or chords on a number), flags it and chords
+    /// it with the A, B and X buttons. Chording follows `GameplaySettings::chord_mode`.
+    fn use_cursor(
+        gamepads: Res<Gamepads>,
+        buttons: Res<Input<GamepadButton>>,
+        board: Option<Res<Board>>,
+        gameplay: Res<GameplaySettings>,
+        mut cursor: ResMut<TileCursor>,
+        mut tile_trigger_event_writer: EventWriter<TileTriggerEvent>,
+        mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        let just_pressed = |button_type| {
+            gamepads
+                .iter()
+                .any(|gamepad| buttons.just_pressed(GamepadButton::new(gamepad, button_type)))
+        };
+        let coordinates = cursor.coordinates;
+
+        if just_pressed(UNCOVER_BUTTON) {
+            info!(
+                "[A] button pressed. Trying to uncover tile on {}",
+                coordinates
+            );
+            if board.covered_tiles.contains_key(&coordinates) || gameplay.allows_chord(false) {
+                tile_trigger_event_writer.send(TileTriggerEvent { coordinates });
+            } else {
+                info!("Chording by uncovering a number is disabled");
+            }
+            cursor.visible = true;
+        }
+
+        if just_pressed(CHORD_BUTTON) {
+            info!("[X] button pressed. Trying to chord on {}", coordinates);
+            if board.covered_tiles.contains_key(&coordinates) {
+                warn!("Only uncovered numbers can be chorded");
+            } else if !gameplay.allows_chord(true) {
+                info!("Chording is disabled");
+            } else {
+                tile_trigger_event_writer.send(TileTriggerEvent { coordinates });
+            }
+            cursor.visible = true;
+        }
+
+        if just_pressed(FLAG_BUTTON) {
+            info!("[B] button pressed. Trying to mark tile on {}", coordinates);
+            tile_mark_event_writer.send(TileMarkEvent(coordinates));
+            cursor.visible = true;
+        }
+    }
+
+    /// This system zooms while the shoulder buttons are held and pans with the right stick.
+    fn zoom_and_pan(
+        gamepads: Res<Gamepads>,
+        buttons: Res<Input<GamepadButton>>,
+        axes: Res<Axis<GamepadAxis>>,
+        time: Res<Time>,
+        mut camera_query: Query<
+            (&mut Transform, &mut OrthographicProjection),
+            With<Minesweeper2dCamera>,
+        >,
+    ) {
+        for gamepad in gamepads.iter() {
+            let pressed = |button_type| buttons.pressed(GamepadButton::new(gamepad, button_type));
+            let axis = |axis_type| {
+                axes.get(GamepadAxis::new(gamepad, axis_type))
+                    .unwrap_or_default()
+            };
+
+            let mut zoom_steps = 0f32;
+            if pressed(ZOOM_IN_BUTTON) {
+                zoom_steps += ZOOM_SPEED * time.delta_seconds();
+            }
+            if pressed(ZOOM_OUT_BUTTON) {
+                zoom_steps -= ZOOM_SPEED * time.delta_seconds();
+            }
+
+            let mut pan = Vec2::new(
+                axis(GamepadAxisType::RightStickX),
+                axis(GamepadAxisType::RightStickY),
+            );
+            if pan.length() < PAN_DEADZONE {
+                pan = Vec2::ZERO;
+            }
+
+            if zoom_steps == 0f32 && pan == Vec2::ZERO {
+                continue;
+            }
+            for (mut transform, mut projection) in camera_query.iter_mut() {
+                CameraPlugin::zoom(&mut projection, zoom_steps);
+                CameraPlugin::pan(
+                    &mut transform,
+                    &projection,
+                    pan.clamp_length_max(1f32),
+                    &time,
+                );
+            }
+        }
+    }
+
+    /// This system pauses the game when pressing Start during a game and resumes it when paused.
+    /// From the main menu it continues the paused game or starts a new one. The other screens
+    /// ignore it, they may be waiting for a binding or hold unsaved edits.
+    fn open_menu(
+        gamepads: Res<Gamepads>,
+        buttons: Res<Input<GamepadButton>>,
+        current_state: Res<State<AppState>>,
+        mut next_state: ResMut<NextState<AppState>>,
+    ) {
+        let pressed = gamepads
+            .iter()
+            .any(|gamepad| buttons.just_pressed(GamepadButton::new(gamepad, MENU_BUTTON)));
+        if !pressed {
+            return;
+        }
+
+        match current_state.get() {
+            AppState::InGame => {
+                info!("[Start] button pressed. Pausing the game");
+                next_state.set(AppState::Paused);
+            }
+            AppState::Paused | AppState::MainMenu => {
+                info!("[Start] button pressed. Playing");
+                next_state.set(AppState::InGame);
+            }
+            _ => {}
+        }
+    }
+
+    /// Shows the button glyphs during a game while at least one gamepad is connected.
+    fn update_hud(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+        gamepads: Res<Gamepads>,
+        state: Res<State<AppState>>,
+        huds: Query<Entity, With<GamepadHud>>,
+    ) {
+        let show = gamepads.iter().next().is_some() && state.get() == &AppState::InGame;
+        match (show, huds.get_single()) {
+            (true, Err(_)) => Self::spawn_hud(&mut commands, &asset_server, &localization),
+            (false, Ok(entity)) => commands.entity(entity).despawn_recursive(),
+            _ => {}
+        }
+    }
+
+    fn spawn_hud(commands: &mut Commands, asset_server: &AssetServer, localization: &Localization) {
+        let style = text_style(&asset_server.load(UI_FONT_PATH), 16f32, Color::WHITE);
+
+        commands
+            .spawn((
+                Name::new("Gamepad HUD"),
+                GamepadHud,
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Px(8f32),
+                        left: Val::Px(8f32),
+                        padding: UiRect::all(Val::Px(4f32)),
+                        column_gap: Val::Px(12f32),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: OVERLAY_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                for (label, color, description) in HUD_GLYPHS {
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                column_gap: Val::Px(4f32),
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|hint| {
+                            hint.spawn(NodeBundle {
+                                style: Style {
+                                    min_width: Val::Px(HUD_GLYPH_SIZE),
+                                    height: Val::Px(HUD_GLYPH_SIZE),
+                                    padding: UiRect::horizontal(Val::Px(4f32)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                background_color: color.into(),
+                                ..default()
+                            })
+                            .with_children(|glyph| {
+                                glyph.spawn(TextBundle::from_section(label, style.clone()));
+                            });
+                            hint.spawn(TextBundle::from_section(
+                                localization.text(description),
+                                style.clone(),
+                            ));
+                        });
+                }
+            });
+    }
+}
@@ -0,0 +1,4 @@
+pub use gamepad_plugin::GamepadPlugin;
+
+#[allow(clippy::module_inception)]
+mod gamepad_plugin;
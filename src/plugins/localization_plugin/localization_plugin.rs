@@ -0,0 +1,144 @@
+use std::path::Path;
+
+use ab_glyph::Font as _;
+use bevy::prelude::*;
+
+use crate::persistence;
+use crate::resources::Localization;
+use crate::systems::UI_FONT_PATH;
+
+/// Fonts of the UI texts, in order of preference. The first one is the font every screen is
+/// spawned with, texts using characters it lacks switch to the next font covering them. Fonts
+/// missing from the assets are skipped.
+const FONT_CHAIN: [&str; 2] = [UI_FONT_PATH, "fonts/dejavu/DejaVuSans.ttf"];
+/// Fonts of the operating system covering Japanese, the first one found is added at the end of
+/// the chain. No bundled font covers Japanese.
+const SYSTEM_FONTS: [&str; 8] = [
+    "C:\\Windows\\Fonts\\YuGothM.ttc",
+    "C:\\Windows\\Fonts\\msgothic.ttc",
+    "/System/Library/Fonts/ヒラギノ角ゴシック W3.ttc",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-sans-cjk-fonts/NotoSansCJK-Regular.ttc",
+];
+
+/// Loaded fonts of the chain, see `FONT_CHAIN`.
+#[derive(Debug, Default, Resource)]
+struct FallbackFonts(Vec<Handle<Font>>);
+
+impl FallbackFonts {
+    /// First font with a glyph for every character of the text, or the one covering the most.
+    fn font_for(&self, value: &str, fonts: &Assets<Font>) -> Option<Handle<Font>> {
+        let mut best: Option<(&Handle<Font>, usize)> = None;
+        for handle in self.0.iter() {
+            let Some(font) = fonts.get(handle) else {
+                continue;
+            };
+            let missing = value
+                .chars()
+                .filter(|c| !c.is_whitespace() && font.font.glyph_id(*c).0 == 0)
+                .count();
+            if missing == 0 {
+                return Some(handle.clone());
+            }
+            if !matches!(best, Some((_, best_missing)) if best_missing <= missing) {
+                best = Some((handle, missing));
+            }
+        }
+        best.map(|(handle, _)| handle.clone())
+    }
+}
+
+/// Translated UI texts, see `Localization`, and the font fallback chain rendering them.
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Localization::load());
+        app.init_resource::<FallbackFonts>();
+
+        app.add_systems(Startup, Self::load_fallback_fonts);
+        // Texts are changed during the update, the layout uses the font
+        app.add_systems(
+            PostUpdate,
+            Self::apply_font_fallback.before(bevy::ui::UiSystem::Layout),
+        );
+
+        info!("Loaded Localization Plugin");
+    }
+}
+
+impl LocalizationPlugin {
+    fn load_fallback_fonts(
+        asset_server: Res<AssetServer>,
+        mut fonts: ResMut<Assets<Font>>,
+        mut fallback_fonts: ResMut<FallbackFonts>,
+    ) {
+        let assets_directory = persistence::assets_directory();
+        for path in FONT_CHAIN {
+            if assets_directory.join(path).is_file() {
+                fallback_fonts.0.push(asset_server.load(path));
+            }
+        }
+
+        let system_font = SYSTEM_FONTS.iter().map(Path::new).find_map(|path| {
+            let bytes = std::fs::read(path).ok()?;
+            match Font::try_from_bytes(bytes) {
+                Ok(font) => {
+                    info!("Using system font {} as fallback", path.display());
+                    Some(font)
+                }
+                Err(e) => {
+                    warn!("Failed to read system font {}: {}", path.display(), e);
+                    None
+                }
+            }
+        });
+        match system_font {
+            Some(font) => fallback_fonts.0.push(fonts.add(font)),
+            None => warn!("No system font covering Japanese found, Japanese texts will not render"),
+        }
+    }
+
+    /// Switches texts using a font of the chain to the first font able to render them. Runs
+    /// after every text change, and for all texts once a font of the chain is loaded.
+    fn apply_font_fallback(
+        fallback_fonts: Res<FallbackFonts>,
+        fonts: Res<Assets<Font>>,
+        mut font_events: EventReader<AssetEvent<Font>>,
+        mut texts: Query<&mut Text>,
+    ) {
+        // Counted instead of using `any`, which would leave the remaining events unread
+        let chain_loaded = font_events
+            .read()
+            .filter(|event| match event {
+                // Fonts read from the system are added directly instead of being loaded
+                AssetEvent::Added { id } | AssetEvent::LoadedWithDependencies { id } => {
+                    fallback_fonts.0.iter().any(|handle| handle.id() == *id)
+                }
+                _ => false,
+            })
+            .count()
+            > 0;
+
+        for mut text in texts.iter_mut() {
+            if !text.is_changed() && !chain_loaded {
+                continue;
+            }
+            for index in 0..text.sections.len() {
+                let section = &text.sections[index];
+                if !fallback_fonts.0.contains(&section.style.font) {
+                    continue;
+                }
+                let Some(font) = fallback_fonts.font_for(&section.value, &fonts) else {
+                    continue;
+                };
+                if font != section.style.font {
+                    text.sections[index].style.font = font;
+                }
+            }
+        }
+    }
+}
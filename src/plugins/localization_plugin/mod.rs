@@ -0,0 +1,4 @@
+pub use localization_plugin::LocalizationPlugin;
+
+#[allow(clippy::module_inception)]
+mod localization_plugin;
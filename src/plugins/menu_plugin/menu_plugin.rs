@@ -0,0 +1,296 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use bevy::window::{WindowFocused, WindowResized};
+
+use crate::components::{MenuButton, MenuScreen};
+use crate::plugins::BoardPlugin;
+use crate::resources::{
+    Action, ActivePuzzle, Board, BoardLayout, GameTimer, InputMap, Localization,
+};
+use crate::systems::{
+    text_style, BUTTON_COLOR, BUTTON_HOVERED_COLOR, MENU_BACKGROUND_COLOR, UI_FONT_PATH,
+};
+use crate::AppState;
+
+const DISABLED_TEXT_COLOR: Color = Color::GRAY;
+const MAIN_MENU: [MenuButton; 7] = [
+    MenuButton::NewGame,
+    MenuButton::Continue,
+    MenuButton::Daily,
+    MenuButton::Puzzles,
+    MenuButton::Statistics,
+    MenuButton::Settings,
+    MenuButton::Quit,
+];
+const PAUSE_MENU: [MenuButton; 4] = [
+    MenuButton::Continue,
+    MenuButton::NewGame,
+    MenuButton::MainMenu,
+    MenuButton::Quit,
+];
+
+/// The main menu the game starts in, and the pause menu opened during a game or when the window
+/// loses the focus. While paused the board is hidden and the game timer stops, see
+/// `BoardPlugin::paused_states`.
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::MainMenu), Self::spawn_main_menu);
+        app.add_systems(OnEnter(AppState::Paused), Self::spawn_pause_menu);
+        app.add_systems(
+            Update,
+            Self::handle_buttons
+                .run_if(in_state(AppState::MainMenu).or_else(in_state(AppState::Paused))),
+        );
+        app.add_systems(
+            Update,
+            Self::toggle_pause
+                .run_if(in_state(AppState::InGame).or_else(in_state(AppState::Paused))),
+        );
+        // Before the state transition, so the game timer does not tick once more
+        app.add_systems(PreUpdate, Self::pause_on_focus_loss);
+        app.add_systems(OnExit(AppState::MainMenu), Self::despawn_menu);
+        app.add_systems(OnExit(AppState::Paused), Self::despawn_menu);
+
+        info!("Loaded Menu Plugin");
+    }
+}
+
+impl MenuPlugin {
+    fn spawn_main_menu(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+        board: Option<Res<Board>>,
+    ) {
+        let font = asset_server.load(UI_FONT_PATH);
+        Self::spawn_menu(
+            &mut commands,
+            font,
+            &localization,
+            &localization.text("game-title"),
+            None,
+            &MAIN_MENU,
+            board.is_some(),
+        );
+    }
+
+    fn spawn_pause_menu(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+        input_map: Res<InputMap>,
+        game_timer: Option<Res<GameTimer>>,
+    ) {
+        let font = asset_server.load(UI_FONT_PATH);
+        let seconds = game_timer.map_or(0, |timer| timer.elapsed().as_secs());
+        let subtitle = localization.text_with(
+            "pause-subtitle",
+            &[
+                (
+                    "time",
+                    format!("{}:{:02}", seconds / 60, seconds % 60).into(),
+                ),
+                ("key", input_map.label(Action::Pause).into()),
+            ],
+        );
+        Self::spawn_menu(
+            &mut commands,
+            font,
+            &localization,
+            &localization.text("pause-title"),
+            Some(subtitle),
+            &PAUSE_MENU,
+            true,
+        );
+    }
+
+    fn spawn_menu(
+        commands: &mut Commands,
+        font: Handle<Font>,
+        localization: &Localization,
+        title: &str,
+        subtitle: Option<String>,
+        entries: &[MenuButton],
+        can_continue: bool,
+    ) {
+        commands
+            .spawn((
+                Name::new("Menu Screen"),
+                MenuScreen,
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100f32),
+                        height: Val::Percent(100f32),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(10f32),
+                        ..default()
+                    },
+                    background_color: MENU_BACKGROUND_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    title,
+                    text_style(&font, 48f32, Color::WHITE),
+                ));
+                if let Some(subtitle) = subtitle {
+                    parent.spawn(TextBundle::from_section(
+                        subtitle,
+                        text_style(&font, 18f32, Color::GRAY),
+                    ));
+                }
+
+                for entry in entries {
+                    let enabled = *entry != MenuButton::Continue || can_continue;
+                    parent
+                        .spawn((
+                            *entry,
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(300f32),
+                                    padding: UiRect::all(Val::Px(8f32)),
+                                    justify_content: JustifyContent::Center,
+                                    ..default()
+                                },
+                                background_color: BUTTON_COLOR.into(),
+                                ..default()
+                            },
+                        ))
+                        .with_children(|button| {
+                            button.spawn(TextBundle::from_section(
+                                localization.text(Self::message_id(entry)),
+                                text_style(
+                                    &font,
+                                    24f32,
+                                    if enabled {
+                                        Color::WHITE
+                                    } else {
+                                        DISABLED_TEXT_COLOR
+                                    },
+                                ),
+                            ));
+                        });
+                }
+            });
+    }
+
+    fn message_id(entry: &MenuButton) -> &'static str {
+        match entry {
+            MenuButton::NewGame => "menu-new-game",
+            MenuButton::Continue => "menu-continue",
+            MenuButton::Daily => "menu-daily",
+            MenuButton::Puzzles => "menu-puzzles",
+            MenuButton::Statistics => "menu-statistics",
+            MenuButton::Settings => "menu-settings",
+            MenuButton::MainMenu => "menu-main-menu",
+            MenuButton::Quit => "menu-quit",
+        }
+    }
+
+    fn handle_buttons(
+        mut commands: Commands,
+        board: Option<Res<Board>>,
+        mut next_state: ResMut<NextState<AppState>>,
+        mut exit_event_writer: EventWriter<AppExit>,
+        mut buttons: Query<(&Interaction, &MenuButton, &mut BackgroundColor), Changed<Interaction>>,
+    ) {
+        for (interaction, entry, mut background_color) in buttons.iter_mut() {
+            match interaction {
+                Interaction::Pressed => {
+                    info!("{:?} selected", entry);
+                    match entry {
+                        MenuButton::NewGame => {
+                            if let Some(board) = &board {
+                                BoardPlugin::<AppState>::discard_board(&mut commands, board);
+                            }
+                            // Puzzles and imported layouts are replayed by regenerating the
+                            // board, a new game is always a random one
+                            commands.remove_resource::<BoardLayout>();
+                            commands.remove_resource::<ActivePuzzle>();
+                            next_state.set(AppState::InGame);
+                        }
+                        MenuButton::Continue if board.is_some() => {
+                            next_state.set(AppState::InGame);
+                        }
+                        MenuButton::Continue => warn!("There is no game to continue"),
+                        MenuButton::Daily => next_state.set(AppState::Daily),
+                        MenuButton::Puzzles => next_state.set(AppState::PuzzleSelect),
+                        MenuButton::Statistics => next_state.set(AppState::Statistics),
+                        MenuButton::Settings => next_state.set(AppState::Settings),
+                        MenuButton::MainMenu => next_state.set(AppState::MainMenu),
+                        MenuButton::Quit => exit_event_writer.send(AppExit),
+                    }
+                }
+                Interaction::Hovered => *background_color = BUTTON_HOVERED_COLOR.into(),
+                Interaction::None => *background_color = BUTTON_COLOR.into(),
+            }
+        }
+    }
+
+    /// This system pauses the game when pressing the button bound to `Action::Pause` (Escape by
+    /// default), and resumes it when pressed again in the pause menu.
+    fn toggle_pause(
+        state: Res<State<AppState>>,
+        mut next_state: ResMut<NextState<AppState>>,
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+    ) {
+        if !input_map.just_pressed(Action::Pause, &keys, &buttons) {
+            return;
+        }
+
+        if state.get() == &AppState::InGame {
+            info!(
+                "[{}] pressed. Pausing the game",
+                input_map.label(Action::Pause)
+            );
+            next_state.set(AppState::Paused);
+        } else {
+            info!(
+                "[{}] pressed. Resuming the game",
+                input_map.label(Action::Pause)
+            );
+            next_state.set(AppState::InGame);
+        }
+    }
+
+    /// This system pauses the game when the window loses the focus or is minimised, which some
+    /// platforms only report as a resize to nothing. The game stays paused once the window is
+    /// back, until it is resumed from the pause menu.
+    fn pause_on_focus_loss(
+        state: Res<State<AppState>>,
+        mut next_state: ResMut<NextState<AppState>>,
+        mut focused_event_reader: EventReader<WindowFocused>,
+        mut resized_event_reader: EventReader<WindowResized>,
+    ) {
+        // Counted instead of using `any`, which would leave the remaining events unread
+        let unfocused = focused_event_reader
+            .read()
+            .filter(|event| !event.focused)
+            .count()
+            > 0;
+        let minimised = resized_event_reader
+            .read()
+            .filter(|event| event.width == 0f32 || event.height == 0f32)
+            .count()
+            > 0;
+
+        // Events are read in every state, so none are left over when a game starts
+        if (unfocused || minimised) && state.get() == &AppState::InGame {
+            info!("Window left. Pausing the game");
+            next_state.set(AppState::Paused);
+        }
+    }
+
+    fn despawn_menu(mut commands: Commands, screens: Query<Entity, With<MenuScreen>>) {
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
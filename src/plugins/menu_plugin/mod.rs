@@ -0,0 +1,4 @@
+pub use menu_plugin::MenuPlugin;
+
+#[allow(clippy::module_inception)]
+mod menu_plugin;
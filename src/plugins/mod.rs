@@ -1,8 +1,47 @@
+pub use accessibility_plugin::AccessibilityPlugin;
+pub use animation_plugin::AnimationPlugin;
 pub use board_plugin::BoardCompletedEvent;
 pub use board_plugin::BoardPlugin;
 pub use board_plugin::Bounds2;
 pub use board_plugin::MineExplosionEvent;
 pub use board_plugin::TileMarkEvent;
 pub use board_plugin::TileTriggerEvent;
+pub use camera_plugin::CameraPlugin;
+pub use controls_plugin::ControlsPlugin;
+pub use controls_plugin::InputSet;
+pub use cursor_plugin::CursorPlugin;
+pub use daily_plugin::DailyPlugin;
+pub use editor_plugin::EditorPlugin;
+pub use effects_plugin::EffectsPlugin;
+pub use gamepad_plugin::GamepadPlugin;
+pub use localization_plugin::LocalizationPlugin;
+pub use menu_plugin::MenuPlugin;
+pub use pointer_plugin::PointerPlugin;
+pub use puzzle_plugin::PuzzlePlugin;
+pub use settings_plugin::SettingsPlugin;
+pub use sound_plugin::SoundPlugin;
+pub use statistics_plugin::StatisticsPlugin;
+pub use theme_plugin::ThemePlugin;
+pub use touch_plugin::TouchPlugin;
+pub use window_settings_plugin::WindowSettingsPlugin;
 
+mod accessibility_plugin;
+mod animation_plugin;
 mod board_plugin;
+mod camera_plugin;
+mod controls_plugin;
+mod cursor_plugin;
+mod daily_plugin;
+mod editor_plugin;
+mod effects_plugin;
+mod gamepad_plugin;
+mod localization_plugin;
+mod menu_plugin;
+mod pointer_plugin;
+mod puzzle_plugin;
+mod settings_plugin;
+mod sound_plugin;
+mod statistics_plugin;
+mod theme_plugin;
+mod touch_plugin;
+mod window_settings_plugin;
@@ -0,0 +1,4 @@
+pub use pointer_plugin::PointerPlugin;
+
+#[allow(clippy::module_inception)]
+mod pointer_plugin;
@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+
+use crate::components::TileHoverHighlight;
+use crate::plugins::ThemePlugin;
+use crate::resources::{Board, BoardAssets, TilePointer};
+use crate::systems::handle_mouse_input;
+use crate::AppState;
+
+const HOVER_COLOR: Color = Color::rgba(1f32, 1f32, 1f32, 0.15);
+/// Drawn above covers and flags, below the keyboard cursor.
+const HOVER_Z: f32 = 4f32;
+
+/// Mouse feedback on the board: the tile under the mouse is highlighted, and covers held down
+/// with a mouse button look pressed. Chording presses all covered neighbours.
+pub struct PointerPlugin;
+
+impl Plugin for PointerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TilePointer>();
+
+        app.add_systems(
+            Update,
+            (Self::update_hover_highlight, Self::update_pressed_covers)
+                .after(handle_mouse_input)
+                .run_if(in_state(AppState::InGame)),
+        );
+        app.add_systems(OnExit(AppState::InGame), Self::reset_pointer);
+
+        info!("Loaded Pointer Plugin");
+    }
+}
+
+impl PointerPlugin {
+    fn reset_pointer(mut pointer: ResMut<TilePointer>) {
+        *pointer = TilePointer::default();
+    }
+
+    /// Keeps the highlight on the hovered tile. It is a child of the board, so it is despawned
+    /// with it and follows relayouts.
+    fn update_hover_highlight(
+        mut commands: Commands,
+        board: Option<Res<Board>>,
+        pointer: Res<TilePointer>,
+        mut highlights: Query<
+            (&mut Transform, &mut Sprite, &mut Visibility),
+            With<TileHoverHighlight>,
+        >,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        if !pointer.is_changed() && !board.is_changed() {
+            return;
+        }
+
+        let coordinates = pointer.hovered.unwrap_or_default();
+        let position =
+            Vec2::new(coordinates.x as f32 + 0.5, coordinates.y as f32 + 0.5) * board.tile_size;
+        let size = Vec2::splat(board.tile_size);
+        let visibility = if pointer.hovered.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        match highlights.get_single_mut() {
+            Ok((mut transform, mut sprite, mut highlight_visibility)) => {
+                transform.translation = position.extend(HOVER_Z);
+                sprite.custom_size = Some(size);
+                *highlight_visibility = visibility;
+            }
+            Err(_) => {
+                commands.entity(board.entity).with_children(|parent| {
+                    parent.spawn((
+                        Name::new("Tile Hover"),
+                        TileHoverHighlight,
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: HOVER_COLOR,
+                                custom_size: Some(size),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position.extend(HOVER_Z)),
+                            visibility,
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        }
+    }
+
+    /// Gives the pressed covers the look of an uncovered tile, the classic pressed look, and
+    /// restores them once released or when the mouse leaves the pressed tile.
+    fn update_pressed_covers(
+        board: Option<Res<Board>>,
+        board_assets: Res<BoardAssets>,
+        pointer: Res<TilePointer>,
+        mut covers: Query<(&mut Sprite, &mut Handle<Image>)>,
+        mut pressed_covers: Local<Vec<Entity>>,
+    ) {
+        let Some(board) = board else {
+            pressed_covers.clear();
+            return;
+        };
+        if !pointer.is_changed() && !board.is_changed() && !board_assets.is_changed() {
+            return;
+        }
+
+        let pressed: Vec<Entity> = match pointer.armed() {
+            Some(press) => {
+                let center = std::iter::once(press.coordinates);
+                let tiles: Vec<_> = if press.chord {
+                    center
+                        .chain(board.tile_map.safe_square_at(press.coordinates))
+                        .collect()
+                } else {
+                    center.collect()
+                };
+                tiles
+                    .iter()
+                    .filter_map(|coordinates| board.tile_to_uncover(coordinates))
+                    .copied()
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        for entity in pressed_covers.iter() {
+            if let Ok((mut sprite, mut texture)) = covers.get_mut(*entity) {
+                ThemePlugin::apply_material(
+                    &board_assets.covered_tile_material,
+                    &mut sprite,
+                    &mut texture,
+                );
+            }
+        }
+        for entity in pressed.iter() {
+            if let Ok((mut sprite, mut texture)) = covers.get_mut(*entity) {
+                ThemePlugin::apply_material(&board_assets.tile_material, &mut sprite, &mut texture);
+            }
+        }
+        *pressed_covers = pressed;
+    }
+}
@@ -0,0 +1,4 @@
+pub use puzzle_plugin::PuzzlePlugin;
+
+#[allow(clippy::module_inception)]
+mod puzzle_plugin;
@@ -0,0 +1,260 @@
+use bevy::prelude::*;
+
+use crate::components::{MineCounter, PuzzleButton, PuzzleSelectScreen};
+use crate::plugins::BoardCompletedEvent;
+use crate::resources::{
+    Action, ActivePuzzle, Board, BoardLayout, InputMap, Localization, PuzzlePack, PuzzleProgress,
+};
+use crate::systems::{
+    text_style, BUTTON_COLOR, BUTTON_HOVERED_COLOR, MENU_BACKGROUND_COLOR, OVERLAY_COLOR,
+    UI_FONT_PATH,
+};
+use crate::AppState;
+
+const SOLVED_TEXT_COLOR: Color = Color::GREEN;
+
+pub struct PuzzlePlugin;
+
+impl Plugin for PuzzlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PuzzlePack>();
+        app.insert_resource(PuzzleProgress::load());
+
+        app.add_systems(
+            OnEnter(AppState::PuzzleSelect),
+            (
+                Self::clear_active_puzzle,
+                (Self::reload_pack, Self::spawn_select_screen).chain(),
+            ),
+        );
+        app.add_systems(
+            Update,
+            Self::handle_select_screen_input.run_if(in_state(AppState::PuzzleSelect)),
+        );
+        app.add_systems(
+            Update,
+            Self::record_completion.run_if(in_state(AppState::InGame)),
+        );
+        app.add_systems(Update, Self::update_mine_counter);
+        app.add_systems(OnExit(AppState::PuzzleSelect), Self::despawn_select_screen);
+
+        info!("Loaded Puzzle Plugin");
+    }
+}
+
+impl PuzzlePlugin {
+    /// The layout is only dropped along with a puzzle, a layout imported with `--board` is kept.
+    fn clear_active_puzzle(mut commands: Commands, active_puzzle: Option<Res<ActivePuzzle>>) {
+        if active_puzzle.is_some() {
+            commands.remove_resource::<ActivePuzzle>();
+            commands.remove_resource::<BoardLayout>();
+        }
+    }
+
+    /// Puzzles are reloaded every time the selection is opened, so levels saved from the editor
+    /// show up without restarting the game.
+    fn reload_pack(mut pack: ResMut<PuzzlePack>) {
+        *pack = PuzzlePack::load();
+        info!(
+            "Loaded {} puzzles from {} and {}",
+            pack.levels.len(),
+            PuzzlePack::directory().display(),
+            PuzzlePack::user_directory().display()
+        );
+    }
+
+    fn spawn_select_screen(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+        input_map: Res<InputMap>,
+        pack: Res<PuzzlePack>,
+        progress: Res<PuzzleProgress>,
+    ) {
+        let font = asset_server.load(UI_FONT_PATH);
+
+        commands
+            .spawn((
+                Name::new("Puzzle Select Screen"),
+                PuzzleSelectScreen,
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100f32),
+                        height: Val::Percent(100f32),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(10f32),
+                        ..default()
+                    },
+                    background_color: MENU_BACKGROUND_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    localization.text("puzzles-title"),
+                    text_style(&font, 48f32, Color::WHITE),
+                ));
+
+                if pack.levels.is_empty() {
+                    parent.spawn(TextBundle::from_section(
+                        localization.text("puzzles-none"),
+                        text_style(&font, 24f32, Color::GRAY),
+                    ));
+                }
+
+                for (index, level) in pack.levels.iter().enumerate() {
+                    let solved = progress.is_completed(&level.id);
+                    parent
+                        .spawn((
+                            PuzzleButton(index),
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(400f32),
+                                    padding: UiRect::all(Val::Px(8f32)),
+                                    justify_content: JustifyContent::SpaceBetween,
+                                    ..default()
+                                },
+                                background_color: BUTTON_COLOR.into(),
+                                ..default()
+                            },
+                        ))
+                        .with_children(|button| {
+                            button.spawn(TextBundle::from_section(
+                                format!("{}. {}", index + 1, level.name),
+                                text_style(&font, 24f32, Color::WHITE),
+                            ));
+                            if solved {
+                                button.spawn(TextBundle::from_section(
+                                    localization.text("puzzles-solved"),
+                                    text_style(&font, 24f32, SOLVED_TEXT_COLOR),
+                                ));
+                            }
+                        });
+                }
+
+                parent.spawn(TextBundle::from_section(
+                    localization.text_with(
+                        "hint-back",
+                        &[("key", input_map.label(Action::Back).into())],
+                    ),
+                    text_style(&font, 18f32, Color::GRAY),
+                ));
+            });
+    }
+
+    fn handle_select_screen_input(
+        mut commands: Commands,
+        mut next_state: ResMut<NextState<AppState>>,
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        mouse_buttons: Res<Input<MouseButton>>,
+        pack: Res<PuzzlePack>,
+        mut buttons: Query<
+            (&Interaction, &PuzzleButton, &mut BackgroundColor),
+            Changed<Interaction>,
+        >,
+    ) {
+        for (interaction, button, mut background_color) in buttons.iter_mut() {
+            match interaction {
+                Interaction::Pressed => {
+                    let Some(level) = pack.levels.get(button.0) else {
+                        continue;
+                    };
+                    info!("Starting puzzle '{}'", level.name);
+                    commands.insert_resource(level.layout.clone());
+                    commands.insert_resource(ActivePuzzle(button.0));
+                    next_state.set(AppState::InGame);
+                }
+                Interaction::Hovered => *background_color = BUTTON_HOVERED_COLOR.into(),
+                Interaction::None => *background_color = BUTTON_COLOR.into(),
+            }
+        }
+
+        if input_map.just_pressed(Action::Back, &keys, &mouse_buttons) {
+            info!(
+                "[{}] pressed. Leaving puzzle selection",
+                input_map.label(Action::Back)
+            );
+            next_state.set(AppState::MainMenu);
+        }
+    }
+
+    /// Puzzles can rely on the number of mines left, see `Solver`, so it is shown while one is
+    /// played. The count goes negative when more tiles are flagged than there are mines.
+    fn update_mine_counter(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+        state: Res<State<AppState>>,
+        active_puzzle: Option<Res<ActivePuzzle>>,
+        board: Option<Res<Board>>,
+        mut counters: Query<(Entity, &mut Text), With<MineCounter>>,
+    ) {
+        let board = board.filter(|_| active_puzzle.is_some() && state.get() == &AppState::InGame);
+        let Some(board) = board else {
+            for (entity, _) in counters.iter() {
+                commands.entity(entity).despawn();
+            }
+            return;
+        };
+
+        let mines_left = board.tile_map.mine_count() as i64 - board.marked_tiles.len() as i64;
+        let value = localization.text_with("puzzle-mines-left", &[("count", mines_left.into())]);
+        match counters.get_single_mut() {
+            Ok((_, mut text)) => {
+                if board.is_changed() || localization.is_changed() {
+                    text.sections[0].value = value;
+                }
+            }
+            Err(_) => {
+                commands.spawn((
+                    Name::new("Mine Counter"),
+                    MineCounter,
+                    TextBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(8f32),
+                            left: Val::Px(8f32),
+                            padding: UiRect::all(Val::Px(4f32)),
+                            ..default()
+                        },
+                        background_color: OVERLAY_COLOR.into(),
+                        ..TextBundle::from_section(
+                            value,
+                            text_style(&asset_server.load(UI_FONT_PATH), 20f32, Color::WHITE),
+                        )
+                    },
+                ));
+            }
+        }
+    }
+
+    fn record_completion(
+        mut board_completed_event_reader: EventReader<BoardCompletedEvent>,
+        active_puzzle: Option<Res<ActivePuzzle>>,
+        pack: Res<PuzzlePack>,
+        mut progress: ResMut<PuzzleProgress>,
+    ) {
+        if board_completed_event_reader.read().count() == 0 {
+            return;
+        }
+
+        let Some(level) = active_puzzle.and_then(|active| pack.levels.get(active.0)) else {
+            return;
+        };
+
+        info!("Puzzle '{}' solved!", level.name);
+        progress.complete(&level.id);
+    }
+
+    fn despawn_select_screen(
+        mut commands: Commands,
+        screens: Query<Entity, With<PuzzleSelectScreen>>,
+    ) {
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use settings_plugin::SettingsPlugin;
+
+#[allow(clippy::module_inception)]
+mod settings_plugin;
@@ -0,0 +1,816 @@
+use bevy::prelude::*;
+
+use crate::components::{
+    NumberPreview, OpenControlsButton, Setting, SettingButton, SettingValueText, SettingsScreen,
+};
+use crate::resources::{
+    AccessibilitySettings, Action, AnimationSettings, AudioSettings, BoardAssets, BoardOptions,
+    ChordMode, GameplaySettings, InputMap, Localization, NumberDecoration, NumberPalette, Themes,
+    TileSize, WindowSettings,
+};
+use crate::systems::{
+    text_style, BUTTON_COLOR, BUTTON_HOVERED_COLOR, MENU_BACKGROUND_COLOR, UI_FONT_PATH,
+};
+use crate::AppState;
+
+const SECTIONS: [(&str, &[Setting]); 4] = [
+    (
+        "settings-board",
+        &[
+            Setting::Difficulty,
+            Setting::Width,
+            Setting::Height,
+            Setting::Mines,
+            Setting::SafeStart,
+            Setting::TilePadding,
+            Setting::TileSize,
+        ],
+    ),
+    (
+        "settings-display",
+        &[
+            Setting::Vsync,
+            Setting::Fullscreen,
+            Setting::Theme,
+            Setting::Language,
+            Setting::NumberColors,
+            Setting::NumberShapes,
+        ],
+    ),
+    (
+        "settings-gameplay",
+        &[
+            Setting::QuestionMarks,
+            Setting::Chording,
+            Setting::Animations,
+        ],
+    ),
+    (
+        "settings-audio",
+        &[
+            Setting::MasterVolume,
+            Setting::EffectsVolume,
+            Setting::MusicVolume,
+            Setting::Mute,
+        ],
+    ),
+];
+/// Name message, size and mine count of the classic difficulties.
+const DIFFICULTIES: [(&str, (u16, u16), u16); 3] = [
+    ("difficulty-beginner", (9, 9), 10),
+    ("difficulty-intermediate", (16, 16), 40),
+    ("difficulty-expert", (30, 16), 99),
+];
+const MIN_BOARD_SIZE: u16 = 5;
+const MAX_BOARD_SIZE: u16 = 50;
+const MAX_TILE_PADDING: f32 = 10f32;
+/// Fixed tile sizes offered besides the window adaptive one.
+const FIXED_TILE_SIZES: [f32; 4] = [20f32, 30f32, 40f32, 50f32];
+const CHORD_MODES: [ChordMode; 3] = [
+    ChordMode::Uncover,
+    ChordMode::ChordButton,
+    ChordMode::Disabled,
+];
+const VOLUME_STEP: f32 = 0.1;
+const PREVIEW_TILE_SIZE: f32 = 32f32;
+const PREVIEW_PIP_SIZE: f32 = 3f32;
+
+/// Sent when an arrow of the settings screen is clicked.
+#[derive(Debug, Copy, Clone, Event)]
+struct SettingChangedEvent {
+    setting: Setting,
+    forward: bool,
+    /// Shift-clicks change numbers in bigger steps.
+    fast: bool,
+}
+
+/// The settings screen, opened from the main menu. Changes are saved right away and applied
+/// live, except for the board size and mine count which apply to the next new game.
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SettingChangedEvent>();
+
+        app.add_systems(
+            Update,
+            (
+                Self::spawn_settings_screen,
+                Self::handle_settings_input,
+                Self::handle_buttons,
+                Self::change_board_settings,
+                Self::change_display_settings,
+                Self::change_gameplay_settings,
+                Self::change_audio_settings,
+                Self::update_value_texts,
+                Self::update_number_preview,
+            )
+                .chain()
+                .run_if(in_state(AppState::Settings)),
+        );
+        app.add_systems(OnExit(AppState::Settings), Self::despawn_settings_screen);
+
+        info!("Loaded Settings Plugin");
+    }
+}
+
+impl SettingsPlugin {
+    /// Rebuilds the screen whenever the language changes.
+    fn spawn_settings_screen(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+        input_map: Res<InputMap>,
+        screens: Query<Entity, With<SettingsScreen>>,
+    ) {
+        if !localization.is_changed() && !screens.is_empty() {
+            return;
+        }
+
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+
+        let font = asset_server.load(UI_FONT_PATH);
+
+        commands
+            .spawn((
+                Name::new("Settings Screen"),
+                SettingsScreen,
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100f32),
+                        height: Val::Percent(100f32),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(10f32),
+                        ..default()
+                    },
+                    background_color: MENU_BACKGROUND_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    localization.text("settings-title"),
+                    text_style(&font, 48f32, Color::WHITE),
+                ));
+
+                // Board and display on the left, gameplay and audio on the right
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            column_gap: Val::Px(40f32),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|columns| {
+                        for sections in SECTIONS.chunks(2) {
+                            columns
+                                .spawn(NodeBundle {
+                                    style: Style {
+                                        flex_direction: FlexDirection::Column,
+                                        row_gap: Val::Px(6f32),
+                                        ..default()
+                                    },
+                                    ..default()
+                                })
+                                .with_children(|column| {
+                                    for (title, settings) in sections {
+                                        Self::spawn_section(
+                                            column,
+                                            &localization,
+                                            title,
+                                            settings,
+                                            &font,
+                                        );
+                                    }
+                                });
+                        }
+                    });
+
+                parent
+                    .spawn((
+                        OpenControlsButton,
+                        ButtonBundle {
+                            style: Style {
+                                padding: UiRect::axes(Val::Px(16f32), Val::Px(4f32)),
+                                ..default()
+                            },
+                            background_color: BUTTON_COLOR.into(),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|button| {
+                        button.spawn(TextBundle::from_section(
+                            localization.text("settings-controls"),
+                            text_style(&font, 18f32, Color::WHITE),
+                        ));
+                    });
+
+                parent.spawn(TextBundle::from_section(
+                    localization.text("settings-hint"),
+                    text_style(&font, 16f32, Color::GRAY),
+                ));
+                parent.spawn(TextBundle::from_section(
+                    localization.text_with(
+                        "hint-back",
+                        &[("key", input_map.label(Action::Back).into())],
+                    ),
+                    text_style(&font, 18f32, Color::GRAY),
+                ));
+            });
+    }
+
+    fn spawn_section(
+        parent: &mut ChildBuilder,
+        localization: &Localization,
+        title: &str,
+        settings: &[Setting],
+        font: &Handle<Font>,
+    ) {
+        parent.spawn(TextBundle::from_section(
+            localization.text(title),
+            text_style(font, 24f32, Color::WHITE),
+        ));
+
+        parent
+            .spawn(NodeBundle {
+                style: Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![
+                        GridTrack::px(150f32),
+                        GridTrack::px(28f32),
+                        GridTrack::px(130f32),
+                        GridTrack::px(28f32),
+                    ],
+                    row_gap: Val::Px(4f32),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|grid| {
+                for setting in settings {
+                    grid.spawn(TextBundle::from_section(
+                        localization.text(Self::message_id(*setting)),
+                        text_style(font, 16f32, Color::GRAY),
+                    ));
+                    Self::spawn_arrow(grid, *setting, false, font);
+                    grid.spawn((
+                        SettingValueText(*setting),
+                        TextBundle::from_section("", text_style(font, 16f32, Color::WHITE))
+                            .with_text_alignment(TextAlignment::Center)
+                            .with_style(Style {
+                                justify_self: JustifySelf::Center,
+                                ..default()
+                            }),
+                    ));
+                    Self::spawn_arrow(grid, *setting, true, font);
+                }
+            });
+
+        // The numbers are previewed right below their options
+        if settings.contains(&Setting::NumberColors) {
+            parent.spawn((
+                NumberPreview,
+                NodeBundle {
+                    style: Style {
+                        align_self: AlignSelf::Center,
+                        column_gap: Val::Px(4f32),
+                        ..default()
+                    },
+                    ..default()
+                },
+            ));
+        }
+    }
+
+    fn spawn_arrow(
+        parent: &mut ChildBuilder,
+        setting: Setting,
+        forward: bool,
+        font: &Handle<Font>,
+    ) {
+        parent
+            .spawn((
+                SettingButton { setting, forward },
+                ButtonBundle {
+                    style: Style {
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    background_color: BUTTON_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|button| {
+                button.spawn(TextBundle::from_section(
+                    if forward { ">" } else { "<" },
+                    text_style(font, 16f32, Color::WHITE),
+                ));
+            });
+    }
+
+    fn message_id(setting: Setting) -> &'static str {
+        match setting {
+            Setting::Difficulty => "setting-difficulty",
+            Setting::Width => "setting-width",
+            Setting::Height => "setting-height",
+            Setting::Mines => "setting-mines",
+            Setting::SafeStart => "setting-safe-start",
+            Setting::TilePadding => "setting-tile-padding",
+            Setting::TileSize => "setting-tile-size",
+            Setting::Vsync => "setting-vsync",
+            Setting::Fullscreen => "setting-fullscreen",
+            Setting::Theme => "setting-theme",
+            Setting::Language => "setting-language",
+            Setting::NumberColors => "setting-number-colors",
+            Setting::NumberShapes => "setting-number-shapes",
+            Setting::QuestionMarks => "setting-question-marks",
+            Setting::Chording => "setting-chording",
+            Setting::Animations => "setting-animations",
+            Setting::MasterVolume => "setting-master-volume",
+            Setting::EffectsVolume => "setting-effects-volume",
+            Setting::MusicVolume => "setting-music-volume",
+            Setting::Mute => "setting-mute",
+        }
+    }
+
+    /// This system leaves the settings when pressing the button bound to `Action::Back` (Escape
+    /// by default).
+    fn handle_settings_input(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        mut next_state: ResMut<NextState<AppState>>,
+    ) {
+        if input_map.just_pressed(Action::Back, &keys, &buttons) {
+            info!(
+                "[{}] pressed. Leaving the settings",
+                input_map.label(Action::Back)
+            );
+            next_state.set(AppState::MainMenu);
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn handle_buttons(
+        keys: Res<Input<KeyCode>>,
+        mut next_state: ResMut<NextState<AppState>>,
+        mut setting_changed_event_writer: EventWriter<SettingChangedEvent>,
+        mut buttons: Query<
+            (
+                &Interaction,
+                Option<&SettingButton>,
+                Option<&OpenControlsButton>,
+                &mut BackgroundColor,
+            ),
+            (
+                Changed<Interaction>,
+                Or<(With<SettingButton>, With<OpenControlsButton>)>,
+            ),
+        >,
+    ) {
+        let fast = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        for (interaction, setting_button, controls_button, mut background_color) in
+            buttons.iter_mut()
+        {
+            match interaction {
+                Interaction::Pressed => {
+                    if let Some(SettingButton { setting, forward }) = setting_button {
+                        setting_changed_event_writer.send(SettingChangedEvent {
+                            setting: *setting,
+                            forward: *forward,
+                            fast,
+                        });
+                    }
+                    if controls_button.is_some() {
+                        next_state.set(AppState::Controls);
+                    }
+                }
+                Interaction::Hovered => *background_color = BUTTON_HOVERED_COLOR.into(),
+                Interaction::None => *background_color = BUTTON_COLOR.into(),
+            }
+        }
+    }
+
+    fn change_board_settings(
+        mut board_options: ResMut<BoardOptions>,
+        mut setting_changed_event_reader: EventReader<SettingChangedEvent>,
+    ) {
+        let mut options = *board_options;
+        let mut changed = false;
+        for event in setting_changed_event_reader.read() {
+            let direction = if event.forward { 1i32 } else { -1i32 };
+            let (width, height) = &mut options.map_size;
+            match event.setting {
+                Setting::Difficulty => {
+                    let current = Self::difficulty(&options);
+                    let next = match current {
+                        Some(index) => Self::cycle(index, direction, DIFFICULTIES.len()),
+                        None if event.forward => 0,
+                        None => DIFFICULTIES.len() - 1,
+                    };
+                    let (_, map_size, mine_count) = DIFFICULTIES[next];
+                    options.map_size = map_size;
+                    options.mine_count = mine_count;
+                }
+                Setting::Width => {
+                    let step = if event.fast { 5 } else { 1 };
+                    *width = Self::step(*width, direction * step, MIN_BOARD_SIZE, MAX_BOARD_SIZE);
+                }
+                Setting::Height => {
+                    let step = if event.fast { 5 } else { 1 };
+                    *height = Self::step(*height, direction * step, MIN_BOARD_SIZE, MAX_BOARD_SIZE);
+                }
+                Setting::Mines => {
+                    let step = if event.fast { 10 } else { 1 };
+                    options.mine_count = Self::step(
+                        options.mine_count,
+                        direction * step,
+                        1,
+                        Self::max_mines(options.map_size),
+                    );
+                }
+                Setting::SafeStart => options.safe_start_enabled = !options.safe_start_enabled,
+                Setting::TilePadding => {
+                    options.tile_padding =
+                        (options.tile_padding + direction as f32).clamp(0f32, MAX_TILE_PADDING);
+                }
+                Setting::TileSize => {
+                    // The window adaptive size comes first
+                    let current = match options.tile_size {
+                        TileSize::Fixed(size) => FIXED_TILE_SIZES
+                            .iter()
+                            .position(|fixed| *fixed == size)
+                            .map_or(0, |index| index + 1),
+                        TileSize::WindowAdaptive { .. } => 0,
+                    };
+                    options.tile_size =
+                        match Self::cycle(current, direction, FIXED_TILE_SIZES.len() + 1) {
+                            0 => TileSize::default(),
+                            index => TileSize::Fixed(FIXED_TILE_SIZES[index - 1]),
+                        };
+                }
+                _ => continue,
+            }
+            options.mine_count = options.mine_count.min(Self::max_mines(options.map_size));
+            changed = true;
+        }
+
+        if changed {
+            *board_options = options;
+            board_options.save();
+        }
+    }
+
+    fn change_display_settings(
+        mut window_settings: ResMut<WindowSettings>,
+        mut themes: Option<ResMut<Themes>>,
+        mut localization: ResMut<Localization>,
+        mut accessibility_settings: ResMut<AccessibilitySettings>,
+        mut setting_changed_event_reader: EventReader<SettingChangedEvent>,
+    ) {
+        for event in setting_changed_event_reader.read() {
+            let direction = if event.forward { 1 } else { -1 };
+            match event.setting {
+                // The window settings are applied and saved by the `WindowSettingsPlugin`
+                Setting::Vsync => window_settings.vsync = !window_settings.vsync,
+                Setting::Fullscreen => window_settings.fullscreen = !window_settings.fullscreen,
+                Setting::Theme => {
+                    if let Some(themes) = themes.as_mut() {
+                        if event.forward {
+                            themes.select_next();
+                        } else {
+                            themes.select_previous();
+                        }
+                    }
+                }
+                Setting::Language => {
+                    if event.forward {
+                        localization.select_next();
+                    } else {
+                        localization.select_previous();
+                    }
+                    localization.save();
+                }
+                // The number colours are applied by the `ThemePlugin`
+                Setting::NumberColors => {
+                    let palettes = NumberPalette::ALL;
+                    let current = palettes
+                        .iter()
+                        .position(|palette| *palette == accessibility_settings.number_palette)
+                        .unwrap_or_default();
+                    accessibility_settings.number_palette =
+                        palettes[Self::cycle(current, direction, palettes.len())];
+                    accessibility_settings.save();
+                }
+                Setting::NumberShapes => {
+                    let decorations = NumberDecoration::ALL;
+                    let current = decorations
+                        .iter()
+                        .position(|decoration| {
+                            *decoration == accessibility_settings.number_decoration
+                        })
+                        .unwrap_or_default();
+                    accessibility_settings.number_decoration =
+                        decorations[Self::cycle(current, direction, decorations.len())];
+                    accessibility_settings.save();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn change_gameplay_settings(
+        mut gameplay_settings: ResMut<GameplaySettings>,
+        mut animation_settings: ResMut<AnimationSettings>,
+        mut setting_changed_event_reader: EventReader<SettingChangedEvent>,
+    ) {
+        for event in setting_changed_event_reader.read() {
+            match event.setting {
+                Setting::QuestionMarks => {
+                    gameplay_settings.question_marks = !gameplay_settings.question_marks;
+                    gameplay_settings.save();
+                }
+                Setting::Chording => {
+                    let current = CHORD_MODES
+                        .iter()
+                        .position(|mode| *mode == gameplay_settings.chord_mode)
+                        .unwrap_or_default();
+                    let direction = if event.forward { 1 } else { -1 };
+                    gameplay_settings.chord_mode =
+                        CHORD_MODES[Self::cycle(current, direction, CHORD_MODES.len())];
+                    gameplay_settings.save();
+                }
+                Setting::Animations => {
+                    animation_settings.toggle();
+                    animation_settings.save();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn change_audio_settings(
+        mut audio_settings: ResMut<AudioSettings>,
+        mut setting_changed_event_reader: EventReader<SettingChangedEvent>,
+    ) {
+        for event in setting_changed_event_reader.read() {
+            let channel = match event.setting {
+                Setting::MasterVolume => &mut audio_settings.master,
+                Setting::EffectsVolume => &mut audio_settings.effects,
+                Setting::MusicVolume => &mut audio_settings.music,
+                Setting::Mute => {
+                    audio_settings.master.muted = !audio_settings.master.muted;
+                    audio_settings.save();
+                    continue;
+                }
+                _ => continue,
+            };
+            let step = if event.forward {
+                VOLUME_STEP
+            } else {
+                -VOLUME_STEP
+            };
+            // Rounded so repeated steps land on whole percents
+            channel.volume = ((channel.volume + step) * 100f32)
+                .round()
+                .clamp(0f32, 100f32)
+                / 100f32;
+            audio_settings.save();
+        }
+    }
+
+    fn update_value_texts(
+        localization: Res<Localization>,
+        board_options: Res<BoardOptions>,
+        (window_settings, accessibility_settings): (
+            Res<WindowSettings>,
+            Res<AccessibilitySettings>,
+        ),
+        board_assets: Option<Res<BoardAssets>>,
+        (gameplay_settings, animation_settings): (Res<GameplaySettings>, Res<AnimationSettings>),
+        audio_settings: Res<AudioSettings>,
+        mut texts: Query<(&SettingValueText, &mut Text)>,
+    ) {
+        let changed = board_options.is_changed()
+            || window_settings.is_changed()
+            || accessibility_settings.is_changed()
+            || board_assets
+                .as_ref()
+                .is_some_and(|assets| assets.is_changed())
+            || gameplay_settings.is_changed()
+            || animation_settings.is_changed()
+            || audio_settings.is_changed();
+        let on_off = |value: bool| localization.text(if value { "on" } else { "off" });
+        let percent = |volume: f32| {
+            localization.text_with("percent", &[("value", (volume * 100f32).round().into())])
+        };
+        let pixels = |size: f32| localization.text_with("pixels", &[("value", size.into())]);
+
+        for (SettingValueText(setting), mut text) in texts.iter_mut() {
+            if !changed && !text.is_added() {
+                continue;
+            }
+
+            text.sections[0].value = match setting {
+                Setting::Difficulty => match Self::difficulty(&board_options) {
+                    Some(index) => localization.text(DIFFICULTIES[index].0),
+                    None => localization.text("difficulty-custom"),
+                },
+                Setting::Width => board_options.map_size.0.to_string(),
+                Setting::Height => board_options.map_size.1.to_string(),
+                Setting::Mines => board_options.mine_count.to_string(),
+                Setting::SafeStart => on_off(board_options.safe_start_enabled),
+                Setting::TilePadding => pixels(board_options.tile_padding),
+                Setting::TileSize => match board_options.tile_size {
+                    TileSize::Fixed(size) => pixels(size),
+                    TileSize::WindowAdaptive { .. } => localization.text("tile-size-adaptive"),
+                },
+                Setting::Vsync => on_off(window_settings.vsync),
+                Setting::Fullscreen => on_off(window_settings.fullscreen),
+                Setting::Theme => board_assets
+                    .as_ref()
+                    .map_or_else(String::new, |assets| assets.label.clone()),
+                Setting::Language => localization.language_name(),
+                Setting::NumberColors => {
+                    localization.text(match accessibility_settings.number_palette {
+                        NumberPalette::Theme => "number-palette-theme",
+                        NumberPalette::Deuteranopia => "number-palette-deuteranopia",
+                        NumberPalette::Protanopia => "number-palette-protanopia",
+                        NumberPalette::Tritanopia => "number-palette-tritanopia",
+                        NumberPalette::Monochrome => "number-palette-monochrome",
+                    })
+                }
+                Setting::NumberShapes => {
+                    localization.text(match accessibility_settings.number_decoration {
+                        NumberDecoration::None => "off",
+                        NumberDecoration::Dice => "number-decoration-dice",
+                        NumberDecoration::Glyphs => "number-decoration-glyphs",
+                    })
+                }
+                Setting::QuestionMarks => on_off(gameplay_settings.question_marks),
+                Setting::Chording => localization.text(match gameplay_settings.chord_mode {
+                    ChordMode::Uncover => "chord-mode-uncover",
+                    ChordMode::ChordButton => "chord-mode-chord-button",
+                    ChordMode::Disabled => "chord-mode-disabled",
+                }),
+                Setting::Animations => on_off(animation_settings.is_enabled()),
+                Setting::MasterVolume => percent(audio_settings.master.volume),
+                Setting::EffectsVolume => percent(audio_settings.effects.volume),
+                Setting::MusicVolume => percent(audio_settings.music.volume),
+                Setting::Mute => on_off(audio_settings.master.muted),
+            };
+        }
+    }
+
+    /// Draws the numbers 1 to 8 on tiles of the current theme, with the selected colours and
+    /// decorations.
+    fn update_number_preview(
+        mut commands: Commands,
+        accessibility_settings: Res<AccessibilitySettings>,
+        board_assets: Option<Res<BoardAssets>>,
+        previews: Query<Entity, With<NumberPreview>>,
+        added: Query<(), Added<NumberPreview>>,
+    ) {
+        let Some(board_assets) = board_assets else {
+            return;
+        };
+        if !accessibility_settings.is_changed() && !board_assets.is_changed() && added.is_empty() {
+            return;
+        }
+
+        for entity in previews.iter() {
+            commands
+                .entity(entity)
+                .despawn_descendants()
+                .with_children(|preview| {
+                    for count in 1..=8 {
+                        Self::spawn_preview_tile(
+                            preview,
+                            &board_assets,
+                            accessibility_settings.number_decoration,
+                            count,
+                        );
+                    }
+                });
+        }
+    }
+
+    fn spawn_preview_tile(
+        parent: &mut ChildBuilder,
+        board_assets: &BoardAssets,
+        decoration: NumberDecoration,
+        count: u8,
+    ) {
+        let color = board_assets.mine_counter_color(count);
+        let corner = Style {
+            position_type: PositionType::Absolute,
+            right: Val::Px(2f32),
+            bottom: Val::Px(2f32),
+            ..default()
+        };
+
+        parent
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(PREVIEW_TILE_SIZE),
+                    height: Val::Px(PREVIEW_TILE_SIZE),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: board_assets.tile_material.color.into(),
+                ..default()
+            })
+            .with_children(|tile| {
+                tile.spawn(TextBundle::from_section(
+                    count.to_string(),
+                    TextStyle {
+                        font: board_assets.mine_counter_font.clone(),
+                        font_size: PREVIEW_TILE_SIZE * 0.8,
+                        color,
+                    },
+                ));
+                match decoration {
+                    NumberDecoration::None => {}
+                    NumberDecoration::Dice => {
+                        let pips = NumberDecoration::dice_pips(count);
+                        tile.spawn(NodeBundle {
+                            style: Style {
+                                display: Display::Grid,
+                                grid_template_columns: vec![GridTrack::px(PREVIEW_PIP_SIZE); 3],
+                                grid_template_rows: vec![GridTrack::px(PREVIEW_PIP_SIZE); 3],
+                                row_gap: Val::Px(1f32),
+                                column_gap: Val::Px(1f32),
+                                ..corner
+                            },
+                            ..default()
+                        })
+                        .with_children(|pattern| {
+                            // Rows from the top, the pips count upwards
+                            for y in [1, 0, -1] {
+                                for x in [-1, 0, 1] {
+                                    let pip = pips.contains(&(x, y));
+                                    pattern.spawn(NodeBundle {
+                                        background_color: if pip {
+                                            color.into()
+                                        } else {
+                                            Color::NONE.into()
+                                        },
+                                        ..default()
+                                    });
+                                }
+                            }
+                        });
+                    }
+                    NumberDecoration::Glyphs => {
+                        tile.spawn(
+                            TextBundle::from_section(
+                                board_assets.number_glyph(count),
+                                TextStyle {
+                                    font: board_assets.number_glyph_font.clone(),
+                                    font_size: PREVIEW_TILE_SIZE * 0.3,
+                                    color,
+                                },
+                            )
+                            .with_style(corner),
+                        );
+                    }
+                }
+            });
+    }
+
+    fn despawn_settings_screen(
+        mut commands: Commands,
+        screens: Query<Entity, With<SettingsScreen>>,
+    ) {
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    /// Index of the difficulty matching the board options, if any.
+    fn difficulty(options: &BoardOptions) -> Option<usize> {
+        DIFFICULTIES.iter().position(|(_, map_size, mine_count)| {
+            *map_size == options.map_size && *mine_count == options.mine_count
+        })
+    }
+
+    /// Leaves room for an opening around the first uncovered tile.
+    fn max_mines((width, height): (u16, u16)) -> u16 {
+        (width * height).saturating_sub(9).max(1)
+    }
+
+    fn step(value: u16, step: i32, min: u16, max: u16) -> u16 {
+        (value as i32 + step).clamp(min as i32, max as i32) as u16
+    }
+
+    fn cycle(index: usize, direction: i32, len: usize) -> usize {
+        (index as i32 + direction).rem_euclid(len as i32) as usize
+    }
+}
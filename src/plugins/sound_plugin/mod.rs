@@ -0,0 +1,4 @@
+pub use sound_plugin::SoundPlugin;
+
+#[allow(clippy::module_inception)]
+mod sound_plugin;
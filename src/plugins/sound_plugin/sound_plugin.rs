@@ -0,0 +1,255 @@
+use bevy::audio::{AddAudioSource, Volume};
+use bevy::prelude::*;
+
+use crate::components::BackgroundMusic;
+use crate::plugins::{
+    BoardCompletedEvent, InputSet, MineExplosionEvent, TileMarkEvent, TileTriggerEvent,
+};
+use crate::resources::{
+    Action, AudioSettings, Board, BoardAssets, InputMap, Note, SynthSound, Waveform,
+};
+use crate::systems::{mark_tiles, uncover_tiles};
+
+/// Built-in sound effects, used for every sound the theme does not override.
+#[derive(Debug, Resource)]
+struct BuiltinSounds {
+    uncover: Handle<SynthSound>,
+    cascade: Handle<SynthSound>,
+    flag: Handle<SynthSound>,
+    unflag: Handle<SynthSound>,
+    chord: Handle<SynthSound>,
+    explosion: Handle<SynthSound>,
+    win: Handle<SynthSound>,
+}
+
+impl FromWorld for BuiltinSounds {
+    fn from_world(world: &mut World) -> Self {
+        let mut sounds = world.resource_mut::<Assets<SynthSound>>();
+        let mut add =
+            |waveform, notes, volume| sounds.add(SynthSound::new(waveform, notes, volume));
+
+        Self {
+            uncover: add(
+                Waveform::Triangle,
+                vec![Note::sweep(900f32, 600f32, 0.06)],
+                0.5,
+            ),
+            cascade: add(Waveform::Sine, vec![Note::sweep(400f32, 1200f32, 0.3)], 0.4),
+            flag: add(
+                Waveform::Square,
+                vec![Note::sweep(660f32, 990f32, 0.08)],
+                0.15,
+            ),
+            unflag: add(
+                Waveform::Square,
+                vec![Note::sweep(990f32, 660f32, 0.08)],
+                0.15,
+            ),
+            chord: add(
+                Waveform::Triangle,
+                vec![Note::tone(523f32, 0.06), Note::tone(784f32, 0.1)],
+                0.5,
+            ),
+            explosion: add(
+                Waveform::Noise,
+                vec![Note::sweep(3000f32, 200f32, 0.9)],
+                1f32,
+            ),
+            win: add(
+                Waveform::Sine,
+                vec![
+                    Note::tone(523f32, 0.12),
+                    Note::tone(659f32, 0.12),
+                    Note::tone(784f32, 0.12),
+                    Note::tone(1047f32, 0.4),
+                ],
+                0.5,
+            ),
+        }
+    }
+}
+
+/// Plays sound effects in response to the board events, and the theme music.
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_source::<SynthSound>();
+        app.init_resource::<BuiltinSounds>();
+        app.insert_resource(AudioSettings::load());
+
+        app.add_systems(
+            Update,
+            (
+                Self::toggle_mute.in_set(InputSet::Shortcuts),
+                // The board has to be inspected before the events are applied
+                Self::play_tile_sounds
+                    .before(uncover_tiles)
+                    .before(mark_tiles),
+                Self::play_game_sounds,
+                Self::play_music,
+            ),
+        );
+
+        info!("Loaded Sound Plugin");
+    }
+}
+
+impl SoundPlugin {
+    /// This system mutes or unmutes all sounds when pressing the button bound to
+    /// `Action::ToggleMute` (M by default).
+    fn toggle_mute(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        mut settings: ResMut<AudioSettings>,
+    ) {
+        if input_map.just_pressed(Action::ToggleMute, &keys, &buttons) {
+            settings.master.muted = !settings.master.muted;
+            settings.save();
+            info!(
+                "[{}] pressed. Sound is now {}",
+                input_map.label(Action::ToggleMute),
+                if settings.master.muted { "muted" } else { "on" }
+            );
+        }
+    }
+
+    fn play_tile_sounds(
+        mut commands: Commands,
+        board: Option<Res<Board>>,
+        board_assets: Res<BoardAssets>,
+        builtin: Res<BuiltinSounds>,
+        settings: Res<AudioSettings>,
+        mut tile_trigger_event_reader: EventReader<TileTriggerEvent>,
+        mut tile_mark_event_reader: EventReader<TileMarkEvent>,
+    ) {
+        let Some(board) = board else {
+            tile_trigger_event_reader.clear();
+            tile_mark_event_reader.clear();
+            return;
+        };
+        let sounds = &board_assets.sounds;
+        let volume = settings.effects_volume();
+
+        for event in tile_trigger_event_reader.read() {
+            let coordinates = event.coordinates;
+            let tile_map = &board.tile_map;
+            if board.tile_to_uncover(&coordinates).is_some() {
+                // Mines are covered by the explosion sound
+                if tile_map.is_mine_at(coordinates) {
+                    continue;
+                }
+                if tile_map.mine_count_at(coordinates) == 0 {
+                    Self::play(&mut commands, &sounds.cascade, &builtin.cascade, volume);
+                } else {
+                    Self::play(&mut commands, &sounds.uncover, &builtin.uncover, volume);
+                }
+            } else if !board.chord_tiles(coordinates).is_empty() {
+                Self::play(&mut commands, &sounds.chord, &builtin.chord, volume);
+            }
+        }
+
+        for event in tile_mark_event_reader.read() {
+            if !board.covered_tiles.contains_key(&event.0) {
+                continue;
+            }
+            // Question marks are removed like flags
+            if board.marked_tiles.contains(&event.0) || board.question_marks.contains(&event.0) {
+                Self::play(&mut commands, &sounds.unflag, &builtin.unflag, volume);
+            } else {
+                Self::play(&mut commands, &sounds.flag, &builtin.flag, volume);
+            }
+        }
+    }
+
+    fn play_game_sounds(
+        mut commands: Commands,
+        board_assets: Res<BoardAssets>,
+        builtin: Res<BuiltinSounds>,
+        settings: Res<AudioSettings>,
+        mut board_completed_event_reader: EventReader<BoardCompletedEvent>,
+        mut mine_explosion_event_reader: EventReader<MineExplosionEvent>,
+    ) {
+        let sounds = &board_assets.sounds;
+        let volume = settings.effects_volume();
+
+        if mine_explosion_event_reader.read().count() > 0 {
+            Self::play(&mut commands, &sounds.explosion, &builtin.explosion, volume);
+        }
+        if board_completed_event_reader.read().count() > 0 {
+            Self::play(&mut commands, &sounds.win, &builtin.win, volume);
+        }
+    }
+
+    /// Plays the sound file from the theme, or the built-in sound when there is none.
+    fn play(
+        commands: &mut Commands,
+        sound: &Option<Handle<AudioSource>>,
+        builtin: &Handle<SynthSound>,
+        volume: f32,
+    ) {
+        if volume <= 0f32 {
+            return;
+        }
+
+        let settings = PlaybackSettings::DESPAWN.with_volume(Volume::new_absolute(volume));
+        match sound {
+            Some(source) => commands.spawn(AudioBundle {
+                source: source.clone(),
+                settings,
+            }),
+            None => commands.spawn(AudioSourceBundle {
+                source: builtin.clone(),
+                settings,
+            }),
+        };
+    }
+
+    /// Starts the music of the current theme, replacing the previous track, and keeps its volume
+    /// in sync with the settings.
+    fn play_music(
+        mut commands: Commands,
+        board_assets: Res<BoardAssets>,
+        settings: Res<AudioSettings>,
+        music: Query<(Entity, &BackgroundMusic, Option<&AudioSink>)>,
+    ) {
+        let volume = settings.music_volume();
+        let track = &board_assets.sounds.music;
+
+        if settings.is_changed() {
+            for (_, _, sink) in music.iter() {
+                if let Some(sink) = sink {
+                    sink.set_volume(volume);
+                }
+            }
+        }
+
+        if !board_assets.is_changed() {
+            return;
+        }
+
+        for (entity, playing, _) in music.iter() {
+            if track.as_ref() != Some(&playing.0) {
+                info!("Stopping the music");
+                commands.entity(entity).despawn();
+            }
+        }
+
+        let Some(track) = track else {
+            return;
+        };
+        if music.iter().any(|(_, playing, _)| playing.0 == *track) {
+            return;
+        }
+
+        info!("Starting the music");
+        commands.spawn((
+            BackgroundMusic(track.clone()),
+            AudioBundle {
+                source: track.clone(),
+                settings: PlaybackSettings::LOOP.with_volume(Volume::new_absolute(volume)),
+            },
+        ));
+    }
+}
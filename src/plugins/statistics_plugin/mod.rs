@@ -0,0 +1,4 @@
+pub use statistics_plugin::StatisticsPlugin;
+
+#[allow(clippy::module_inception)]
+mod statistics_plugin;
@@ -0,0 +1,242 @@
+use bevy::prelude::*;
+
+use crate::components::StatisticsScreen;
+use crate::plugins::{BoardCompletedEvent, MineExplosionEvent};
+use crate::resources::{
+    Action, ActivePuzzle, Board, BoardLayout, DailyChallenge, GameTimer, InputMap, Localization,
+    Statistics,
+};
+use crate::systems::{stop_game_timer, text_style, MENU_BACKGROUND_COLOR, UI_FONT_PATH};
+use crate::AppState;
+
+/// Records the result of every finished board and shows them on the statistics screen.
+pub struct StatisticsPlugin;
+
+impl Plugin for StatisticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Statistics::load());
+
+        app.add_systems(
+            Update,
+            Self::record_result
+                .after(stop_game_timer)
+                .run_if(in_state(AppState::InGame)),
+        );
+
+        app.add_systems(OnEnter(AppState::Statistics), Self::spawn_statistics_screen);
+        app.add_systems(
+            Update,
+            Self::handle_statistics_input.run_if(in_state(AppState::Statistics)),
+        );
+        app.add_systems(
+            OnExit(AppState::Statistics),
+            Self::despawn_statistics_screen,
+        );
+
+        info!("Loaded Statistics Plugin");
+    }
+}
+
+impl StatisticsPlugin {
+    /// Only the first result of a board counts, uncovering more tiles after losing changes
+    /// nothing. Only random boards are recorded: puzzles start partly solved, and daily
+    /// challenges and imported layouts are not comparable with the other boards of their size.
+    #[allow(clippy::type_complexity)]
+    fn record_result(
+        board: Option<Res<Board>>,
+        (active_puzzle, board_layout, daily_challenge): (
+            Option<Res<ActivePuzzle>>,
+            Option<Res<BoardLayout>>,
+            Option<Res<DailyChallenge>>,
+        ),
+        game_timer: Option<Res<GameTimer>>,
+        mut statistics: ResMut<Statistics>,
+        mut board_completed_event_reader: EventReader<BoardCompletedEvent>,
+        mut mine_explosion_event_reader: EventReader<MineExplosionEvent>,
+        mut recorded_board: Local<Option<Entity>>,
+    ) {
+        let completed = board_completed_event_reader.read().count() > 0;
+        let exploded = mine_explosion_event_reader.read().count() > 0;
+
+        let Some(board) = board else {
+            return;
+        };
+        if (!completed && !exploded) || *recorded_board == Some(board.entity) {
+            return;
+        }
+        *recorded_board = Some(board.entity);
+
+        if active_puzzle.is_some() || board_layout.is_some() || daily_challenge.is_some() {
+            info!("Game finished on a prepared board, not recorded in the statistics");
+            return;
+        }
+
+        if exploded {
+            statistics.record_loss();
+            info!(
+                "Game lost, {} won out of {} played",
+                statistics.won,
+                statistics.played()
+            );
+        } else {
+            let seconds = game_timer.map_or(0f32, |timer| timer.elapsed().as_secs_f32());
+            let label = Statistics::board_label(&board.tile_map);
+            if statistics.record_win(label.clone(), seconds) {
+                info!("New best time on {}: {}", label, Self::format_time(seconds));
+            }
+        }
+    }
+
+    fn spawn_statistics_screen(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        localization: Res<Localization>,
+        input_map: Res<InputMap>,
+        statistics: Res<Statistics>,
+    ) {
+        let font = asset_server.load(UI_FONT_PATH);
+
+        let summary = [
+            ("statistics-played", statistics.played().to_string()),
+            ("statistics-won", statistics.won.to_string()),
+            (
+                "statistics-win-rate",
+                localization.text_with(
+                    "percent",
+                    &[("value", (statistics.win_rate() * 100f32).round().into())],
+                ),
+            ),
+            (
+                "statistics-current-streak",
+                statistics.current_streak.to_string(),
+            ),
+            ("statistics-best-streak", statistics.best_streak.to_string()),
+        ];
+
+        commands
+            .spawn((
+                Name::new("Statistics Screen"),
+                StatisticsScreen,
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100f32),
+                        height: Val::Percent(100f32),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        row_gap: Val::Px(10f32),
+                        ..default()
+                    },
+                    background_color: MENU_BACKGROUND_COLOR.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    localization.text("statistics-title"),
+                    text_style(&font, 48f32, Color::WHITE),
+                ));
+
+                Self::spawn_table(
+                    parent,
+                    summary
+                        .into_iter()
+                        .map(|(id, value)| (localization.text(id), value))
+                        .collect(),
+                    &font,
+                );
+
+                parent.spawn(TextBundle::from_section(
+                    localization.text("statistics-best-times"),
+                    text_style(&font, 32f32, Color::WHITE),
+                ));
+                let best_times: Vec<_> = statistics
+                    .best_times()
+                    .map(|(label, seconds)| {
+                        let board = match Statistics::board_size(label) {
+                            Some((width, height, mines)) => localization.text_with(
+                                "statistics-board",
+                                &[
+                                    ("width", width.into()),
+                                    ("height", height.into()),
+                                    ("mines", mines.into()),
+                                ],
+                            ),
+                            None => label.clone(),
+                        };
+                        (board, Self::format_time(seconds))
+                    })
+                    .collect();
+                if best_times.is_empty() {
+                    parent.spawn(TextBundle::from_section(
+                        localization.text("statistics-no-wins"),
+                        text_style(&font, 24f32, Color::GRAY),
+                    ));
+                } else {
+                    Self::spawn_table(parent, best_times, &font);
+                }
+
+                parent.spawn(TextBundle::from_section(
+                    localization.text_with(
+                        "hint-back",
+                        &[("key", input_map.label(Action::Back).into())],
+                    ),
+                    text_style(&font, 18f32, Color::GRAY),
+                ));
+            });
+    }
+
+    fn spawn_table(parent: &mut ChildBuilder, rows: Vec<(String, String)>, font: &Handle<Font>) {
+        parent
+            .spawn(NodeBundle {
+                style: Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![GridTrack::px(260f32), GridTrack::px(140f32)],
+                    row_gap: Val::Px(4f32),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|grid| {
+                for (name, value) in rows {
+                    grid.spawn(TextBundle::from_section(
+                        name,
+                        text_style(font, 24f32, Color::GRAY),
+                    ));
+                    grid.spawn(TextBundle::from_section(
+                        value,
+                        text_style(font, 24f32, Color::WHITE),
+                    ));
+                }
+            });
+    }
+
+    fn handle_statistics_input(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        mut next_state: ResMut<NextState<AppState>>,
+    ) {
+        if input_map.just_pressed(Action::Back, &keys, &buttons) {
+            info!(
+                "[{}] pressed. Leaving the statistics",
+                input_map.label(Action::Back)
+            );
+            next_state.set(AppState::MainMenu);
+        }
+    }
+
+    fn despawn_statistics_screen(
+        mut commands: Commands,
+        screens: Query<Entity, With<StatisticsScreen>>,
+    ) {
+        for entity in screens.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    fn format_time(seconds: f32) -> String {
+        let seconds = seconds as u32;
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
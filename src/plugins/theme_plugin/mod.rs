@@ -0,0 +1,5 @@
+pub use theme_plugin::ThemePlugin;
+
+mod theme_loader;
+#[allow(clippy::module_inception)]
+mod theme_plugin;
@@ -0,0 +1,251 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy::window::{PrimaryWindow, WindowTheme, WindowThemeChanged};
+
+use crate::components::BoardElement;
+use crate::plugins::theme_plugin::theme_loader::ThemeLoader;
+use crate::plugins::InputSet;
+use crate::resources::{
+    AccessibilitySettings, Action, BoardAssets, InputMap, SpriteMaterial, ThemeDefinition, Themes,
+};
+
+/// Fresh copies of fonts modified on disk, keyed by the original font.
+///
+/// Glyph atlases are cached per font asset and never invalidated when a font is reloaded, so a
+/// modified font has to be swapped for a new asset to be rendered again.
+#[derive(Debug, Default, Resource)]
+struct ReloadedFonts(HashMap<AssetId<Font>, Handle<Font>>);
+
+pub struct ThemePlugin;
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ThemeDefinition>();
+        app.register_asset_loader(ThemeLoader);
+        app.init_resource::<ReloadedFonts>();
+
+        app.add_systems(Startup, Self::load_themes);
+        app.add_systems(
+            Update,
+            (
+                Self::cycle_theme.in_set(InputSet::Shortcuts),
+                Self::follow_window_theme,
+                Self::reload_fonts,
+                Self::apply_theme,
+                Self::reskin_board.run_if(resource_exists_and_changed::<BoardAssets>()),
+            )
+                .chain(),
+        );
+
+        info!("Loaded Theme Plugin");
+    }
+}
+
+impl ThemePlugin {
+    /// Starts loading the themes. Until the selected theme is loaded the board uses the
+    /// built-in default, so a game can be started right away.
+    fn load_themes(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        mut definitions: ResMut<Assets<ThemeDefinition>>,
+        accessibility_settings: Res<AccessibilitySettings>,
+    ) {
+        let themes = Themes::load(&asset_server, &mut definitions);
+        info!("Loading {} themes", themes.len());
+        commands.insert_resource(themes);
+
+        match ThemeDefinition::default()
+            .board_assets(&asset_server, accessibility_settings.number_palette)
+        {
+            Ok(board_assets) => commands.insert_resource(board_assets),
+            Err(e) => error!("Failed to build the default board assets: {}", e),
+        }
+    }
+
+    /// This system switches to the next theme when pressing the button bound to
+    /// `Action::NextTheme` (T by default).
+    fn cycle_theme(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        themes: Option<ResMut<Themes>>,
+    ) {
+        let Some(mut themes) = themes else {
+            return;
+        };
+
+        if input_map.just_pressed(Action::NextTheme, &keys, &buttons) {
+            themes.select_next();
+            info!(
+                "[{}] pressed. Switching to the next theme",
+                input_map.label(Action::NextTheme)
+            );
+        }
+    }
+
+    /// Picks the light or dark theme matching the OS window theme, until the player selects a
+    /// theme themselves.
+    fn follow_window_theme(
+        themes: Option<ResMut<Themes>>,
+        window_query: Query<&Window, With<PrimaryWindow>>,
+        mut theme_changed_events: EventReader<WindowThemeChanged>,
+        mut window_theme: Local<Option<WindowTheme>>,
+    ) {
+        // The window component only holds the theme reported at creation, later changes are
+        // only sent as events
+        let reported = theme_changed_events
+            .read()
+            .last()
+            .map(|event| event.theme)
+            .or(*window_theme)
+            .or_else(|| window_query.get_single().ok()?.window_theme);
+
+        let Some(mut themes) = themes else {
+            return;
+        };
+        if reported == *window_theme && !themes.is_added() {
+            return;
+        }
+        *window_theme = reported;
+
+        let Some(reported) = reported else {
+            return;
+        };
+        let index = themes.index_for(reported);
+        if themes.follow_window_theme && themes.selected() != index {
+            info!("Following the {:?} window theme", reported);
+            themes.select(index);
+        }
+    }
+
+    /// Applies the selected theme when the selection changes and whenever its file is loaded or
+    /// modified.
+    fn apply_theme(
+        mut commands: Commands,
+        themes: Option<Res<Themes>>,
+        definitions: Res<Assets<ThemeDefinition>>,
+        asset_server: Res<AssetServer>,
+        reloaded_fonts: Res<ReloadedFonts>,
+        accessibility_settings: Res<AccessibilitySettings>,
+        mut theme_events: EventReader<AssetEvent<ThemeDefinition>>,
+    ) {
+        let Some(themes) = themes else {
+            return;
+        };
+
+        let current = themes.current().id();
+        let mut reloaded = false;
+        for event in theme_events.read() {
+            match event {
+                AssetEvent::LoadedWithDependencies { id } if *id == current => reloaded = true,
+                AssetEvent::Modified { id } if *id == current => {
+                    info!("Theme file modified, reloading");
+                    reloaded = true;
+                }
+                _ => {}
+            }
+        }
+
+        // The number colours depend on the selected palette
+        if !reloaded && !themes.is_changed() && !accessibility_settings.is_changed() {
+            return;
+        }
+
+        let Some(theme) = definitions.get(current) else {
+            // Not loaded yet, the `LoadedWithDependencies` event will trigger another attempt
+            return;
+        };
+
+        match theme.board_assets(&asset_server, accessibility_settings.number_palette) {
+            Ok(mut board_assets) => {
+                if let Some(font) = reloaded_fonts.0.get(&board_assets.mine_counter_font.id()) {
+                    board_assets.mine_counter_font = font.clone();
+                }
+                info!("Applying theme '{}'", theme.label);
+                commands.insert_resource(board_assets);
+            }
+            Err(e) => error!("Failed to apply theme '{}': {}", theme.label, e),
+        }
+    }
+
+    /// Sprite textures are refreshed by the renderer on their own, fonts need a new asset.
+    fn reload_fonts(
+        mut fonts: ResMut<Assets<Font>>,
+        mut reloaded_fonts: ResMut<ReloadedFonts>,
+        board_assets: Option<ResMut<BoardAssets>>,
+        asset_server: Res<AssetServer>,
+        mut font_events: EventReader<AssetEvent<Font>>,
+    ) {
+        let Some(mut board_assets) = board_assets else {
+            return;
+        };
+
+        for event in font_events.read() {
+            let AssetEvent::Modified { id } = event else {
+                continue;
+            };
+            // Only fonts loaded from files are watched, copies have no path
+            if asset_server.get_path(*id).is_none() {
+                continue;
+            }
+            let Some(font) = fonts.get(*id).cloned() else {
+                continue;
+            };
+
+            info!("Font file modified, reloading");
+            let copy = fonts.add(font);
+            let used_by_board = board_assets.mine_counter_font.id() == *id
+                || reloaded_fonts
+                    .0
+                    .get(id)
+                    .is_some_and(|previous| *previous == board_assets.mine_counter_font);
+            if used_by_board {
+                board_assets.mine_counter_font = copy.clone();
+            }
+            reloaded_fonts.0.insert(*id, copy);
+        }
+    }
+
+    /// Updates the existing board entities to the current `BoardAssets`, so switching themes
+    /// does not require regenerating the board.
+    fn reskin_board(
+        board_assets: Res<BoardAssets>,
+        mut sprites: Query<(&BoardElement, &mut Sprite, &mut Handle<Image>)>,
+        mut texts: Query<(&BoardElement, &mut Text)>,
+    ) {
+        for (element, mut sprite, mut texture) in sprites.iter_mut() {
+            let material = match element {
+                BoardElement::Background => &board_assets.board_material,
+                BoardElement::Tile => &board_assets.tile_material,
+                BoardElement::Cover => &board_assets.covered_tile_material,
+                BoardElement::Flag => &board_assets.flag_material,
+                BoardElement::Mine => &board_assets.mine_material,
+                BoardElement::MineCounter(_) | BoardElement::QuestionMark => continue,
+            };
+            Self::apply_material(material, &mut sprite, &mut texture);
+        }
+
+        for (element, mut text) in texts.iter_mut() {
+            let color = match element {
+                BoardElement::MineCounter(count) => board_assets.mine_counter_color(*count),
+                BoardElement::QuestionMark => board_assets.question_mark_color(),
+                _ => continue,
+            };
+            for section in text.sections.iter_mut() {
+                section.style.color = color;
+                section.style.font = board_assets.mine_counter_font.clone();
+            }
+        }
+    }
+
+    pub fn apply_material(
+        material: &SpriteMaterial,
+        sprite: &mut Sprite,
+        texture: &mut Handle<Image>,
+    ) {
+        sprite.color = material.color;
+        if *texture != material.texture {
+            *texture = material.texture.clone();
+        }
+    }
+}
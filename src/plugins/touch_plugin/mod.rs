@@ -0,0 +1,4 @@
+pub use touch_plugin::TouchPlugin;
+
+#[allow(clippy::module_inception)]
+mod touch_plugin;
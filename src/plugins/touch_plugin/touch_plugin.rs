@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use bevy::input::touch::Touch;
+use bevy::prelude::*;
+
+use crate::components::{
+    Coordinates, LongPressRing, LongPressRingSegment, TouchModeButton, TouchModeText,
+};
+use crate::plugins::{CameraPlugin, TileMarkEvent, TileTriggerEvent};
+use crate::resources::{Board, GameplaySettings, Localization, TouchMode};
+use crate::systems::{text_style, trigger_event_handler, Minesweeper2dCamera, UI_FONT_PATH};
+use crate::AppState;
+
+/// Seconds a tile has to be held for a long press.
+const LONG_PRESS_DURATION: f32 = 0.5;
+/// Seconds before the long press ring shows up, so quick taps do not flash it.
+const RING_DELAY: f32 = 0.1;
+/// Distance in logical pixels a finger can move before a press turns into a drag.
+const TAP_SLOP: f32 = 12f32;
+
+const RING_SEGMENTS: usize = 16;
+/// Ring radius and segment size, relative to the tile size.
+const RING_RADIUS: f32 = 0.65;
+const RING_SEGMENT_SIZE: f32 = 0.16;
+const RING_COLOR: Color = Color::rgba(1f32, 1f32, 1f32, 0.9);
+/// Drawn above covers, flags and the keyboard cursor.
+const RING_Z: f32 = 6f32;
+
+const BUTTON_COLOR: Color = Color::rgba(0.25, 0.25, 0.25, 0.8);
+const FLAG_MODE_BUTTON_COLOR: Color = Color::rgba(0.6, 0.2, 0.2, 0.8);
+
+/// Tile held by a finger.
+struct PressedTile {
+    id: u64,
+    coordinates: Coordinates,
+    held_for: f32,
+    long_pressed: bool,
+}
+
+#[derive(Default, Resource)]
+struct TouchPress {
+    pressed: Option<PressedTile>,
+    /// Tile tapped or long pressed this frame, and whether it was a long press.
+    completed: Option<(Coordinates, bool)>,
+}
+
+/// Touch screen play: tapping uncovers a tile and long pressing flags it, or the other way
+/// around in flag mode. One finger drags the camera, two fingers pinch to zoom and pan.
+pub struct TouchPlugin;
+
+impl Plugin for TouchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TouchMode>();
+        app.init_resource::<TouchPress>();
+
+        app.add_systems(Update, (Self::pinch_and_drag, Self::update_mode_button));
+        app.add_systems(
+            Update,
+            (
+                Self::track_press,
+                Self::toggle_mode,
+                Self::apply_press.before(trigger_event_handler),
+                Self::update_ring,
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+        app.add_systems(OnExit(AppState::InGame), Self::cancel_press);
+
+        info!("Loaded Touch Plugin");
+    }
+}
+
+impl TouchPlugin {
+    /// Follows the finger on a tile until it is lifted, moved away or held long enough for a
+    /// long press. A second finger turns the press into a pinch.
+    fn track_press(
+        touches: Res<Touches>,
+        time: Res<Time>,
+        board: Option<Res<Board>>,
+        camera_query: Query<(&Camera, &GlobalTransform), With<Minesweeper2dCamera>>,
+        mut press: ResMut<TouchPress>,
+    ) {
+        let Some(board) = board else {
+            return;
+        };
+        let press = press.as_mut();
+        if touches.iter().count() > 1 {
+            press.pressed = None;
+            return;
+        }
+
+        for touch in touches.iter_just_pressed() {
+            let Ok((camera, camera_transform)) = camera_query.get_single() else {
+                continue;
+            };
+            press.pressed = board
+                .mouse_position(camera, camera_transform, touch.position())
+                .map(|coordinates| PressedTile {
+                    id: touch.id(),
+                    coordinates,
+                    held_for: 0f32,
+                    long_pressed: false,
+                });
+        }
+
+        let Some(pressed) = press.pressed.as_mut() else {
+            return;
+        };
+        if let Some(touch) = touches.get_pressed(pressed.id) {
+            if touch.distance().length() > TAP_SLOP {
+                press.pressed = None;
+                return;
+            }
+            if !pressed.long_pressed {
+                pressed.held_for += time.delta_seconds();
+                if pressed.held_for >= LONG_PRESS_DURATION {
+                    pressed.long_pressed = true;
+                    info!("Long press on {}", pressed.coordinates);
+                    press.completed = Some((pressed.coordinates, true));
+                }
+            }
+        } else {
+            if touches.just_released(pressed.id) && !pressed.long_pressed {
+                info!("Tap on {}", pressed.coordinates);
+                press.completed = Some((pressed.coordinates, false));
+            }
+            press.pressed = None;
+        }
+    }
+
+    /// Taps on uncovered numbers chord, unless `GameplaySettings::chord_mode` disallows it.
+    fn apply_press(
+        mut press: ResMut<TouchPress>,
+        mode: Res<TouchMode>,
+        board: Option<Res<Board>>,
+        gameplay: Res<GameplaySettings>,
+        mut tile_trigger_event_writer: EventWriter<TileTriggerEvent>,
+        mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
+    ) {
+        let Some((coordinates, long_press)) = press.completed.take() else {
+            return;
+        };
+
+        let covered = board.is_some_and(|board| board.covered_tiles.contains_key(&coordinates));
+        if mode.flags(long_press) {
+            tile_mark_event_writer.send(TileMarkEvent(coordinates));
+        } else if covered || gameplay.allows_chord(false) {
+            tile_trigger_event_writer.send(TileTriggerEvent { coordinates });
+        } else {
+            info!("Chording by tapping a number is disabled");
+        }
+    }
+
+    fn cancel_press(mut press: ResMut<TouchPress>) {
+        *press = TouchPress::default();
+    }
+
+    /// Shows a ring around the pressed tile, filling up until the long press triggers.
+    fn update_ring(
+        mut commands: Commands,
+        board: Option<Res<Board>>,
+        press: Res<TouchPress>,
+        mut rings: Query<(Entity, &mut Transform), With<LongPressRing>>,
+        mut segments: Query<(&LongPressRingSegment, &mut Visibility)>,
+    ) {
+        let progress = press
+            .pressed
+            .as_ref()
+            .filter(|pressed| !pressed.long_pressed && pressed.held_for >= RING_DELAY)
+            .map(|pressed| {
+                let progress = (pressed.held_for - RING_DELAY) / (LONG_PRESS_DURATION - RING_DELAY);
+                (pressed.coordinates, progress)
+            });
+        let (Some(board), Some((coordinates, progress))) = (board, progress) else {
+            for (entity, _) in rings.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            return;
+        };
+
+        let position =
+            Vec2::new(coordinates.x as f32 + 0.5, coordinates.y as f32 + 0.5) * board.tile_size;
+        match rings.get_single_mut() {
+            Ok((_, mut transform)) => {
+                transform.translation = position.extend(RING_Z);
+                let visible_segments = (progress * RING_SEGMENTS as f32).ceil() as usize;
+                for (segment, mut visibility) in segments.iter_mut() {
+                    *visibility = if segment.0 < visible_segments {
+                        Visibility::Inherited
+                    } else {
+                        Visibility::Hidden
+                    };
+                }
+            }
+            Err(_) => Self::spawn_ring(&mut commands, &board, position),
+        }
+    }
+
+    fn spawn_ring(commands: &mut Commands, board: &Board, position: Vec2) {
+        let radius = board.tile_size * RING_RADIUS;
+        let size = Vec2::splat(board.tile_size * RING_SEGMENT_SIZE);
+
+        commands.entity(board.entity).with_children(|parent| {
+            parent
+                .spawn((
+                    Name::new("Long Press Ring"),
+                    LongPressRing,
+                    SpatialBundle::from_transform(Transform::from_translation(
+                        position.extend(RING_Z),
+                    )),
+                ))
+                .with_children(|ring| {
+                    // Clockwise from the top
+                    for index in 0..RING_SEGMENTS {
+                        let angle = FRAC_PI_2 - TAU * index as f32 / RING_SEGMENTS as f32;
+                        let offset = Vec2::new(angle.cos(), angle.sin()) * radius;
+                        ring.spawn((
+                            LongPressRingSegment(index),
+                            SpriteBundle {
+                                sprite: Sprite {
+                                    color: RING_COLOR,
+                                    custom_size: Some(size),
+                                    ..default()
+                                },
+                                transform: Transform::from_translation(offset.extend(0f32)),
+                                visibility: Visibility::Hidden,
+                                ..default()
+                            },
+                        ));
+                    }
+                });
+        });
+    }
+
+    /// This system pans the camera while dragging with one finger, and pinches to zoom and pan
+    /// with two fingers.
+    fn pinch_and_drag(
+        touches: Res<Touches>,
+        mut camera_query: Query<
+            (
+                &Camera,
+                &GlobalTransform,
+                &mut Transform,
+                &mut OrthographicProjection,
+            ),
+            With<Minesweeper2dCamera>,
+        >,
+        mut last_positions: Local<HashMap<u64, Vec2>>,
+    ) {
+        let pressed: Vec<&Touch> = touches.iter().collect();
+        let last = |touch: &Touch| last_positions.get(&touch.id()).copied();
+
+        // Screen coordinates grow downwards, world coordinates upwards
+        let flip = Vec2::new(-1f32, 1f32);
+        match pressed.as_slice() {
+            [first, second] => {
+                if let (Some(first_last), Some(second_last)) = (last(first), last(second)) {
+                    let old_middle = (first_last + second_last) / 2f32;
+                    let new_middle = (first.position() + second.position()) / 2f32;
+                    let old_distance = first_last.distance(second_last);
+                    let new_distance = first.position().distance(second.position());
+
+                    for (camera, camera_transform, mut transform, mut projection) in
+                        camera_query.iter_mut()
+                    {
+                        if old_distance > 0f32 {
+                            let steps = CameraPlugin::zoom_steps(new_distance / old_distance);
+                            if let Some(point) =
+                                camera.viewport_to_world_2d(camera_transform, old_middle)
+                            {
+                                CameraPlugin::zoom_around(
+                                    &mut transform,
+                                    &mut projection,
+                                    point,
+                                    steps,
+                                );
+                            }
+                        }
+                        let delta = (new_middle - old_middle) * flip * projection.scale;
+                        transform.translation += delta.extend(0f32);
+                    }
+                }
+            }
+            [touch] if touch.distance().length() > TAP_SLOP => {
+                if let Some(last) = last(touch) {
+                    for (_, _, mut transform, projection) in camera_query.iter_mut() {
+                        let delta = (touch.position() - last) * flip * projection.scale;
+                        transform.translation += delta.extend(0f32);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        *last_positions = pressed
+            .iter()
+            .map(|touch| (touch.id(), touch.position()))
+            .collect();
+    }
+
+    /// Shows the tap mode button during a game, once the touch screen has been used.
+    fn update_mode_button(
+        mut commands: Commands,
+        asset_server: Res<AssetServer>,
+        touches: Res<Touches>,
+        state: Res<State<AppState>>,
+        mode: Res<TouchMode>,
+        buttons: Query<Entity, With<TouchModeButton>>,
+        mut touch_used: Local<bool>,
+    ) {
+        *touch_used |= touches.iter().next().is_some();
+        let show = *touch_used && state.get() == &AppState::InGame;
+
+        match (show, buttons.get_single()) {
+            (true, Err(_)) => Self::spawn_mode_button(&mut commands, &asset_server, *mode),
+            (false, Ok(entity)) => commands.entity(entity).despawn_recursive(),
+            _ => {}
+        }
+    }
+
+    fn spawn_mode_button(commands: &mut Commands, asset_server: &AssetServer, mode: TouchMode) {
+        commands
+            .spawn((
+                Name::new("Touch Mode Button"),
+                TouchModeButton,
+                ButtonBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(8f32),
+                        right: Val::Px(8f32),
+                        padding: UiRect::all(Val::Px(12f32)),
+                        ..default()
+                    },
+                    background_color: Self::mode_color(mode).into(),
+                    ..default()
+                },
+            ))
+            .with_children(|button| {
+                button.spawn((
+                    TouchModeText,
+                    TextBundle::from_section(
+                        "",
+                        text_style(&asset_server.load(UI_FONT_PATH), 24f32, Color::WHITE),
+                    ),
+                ));
+            });
+    }
+
+    /// Switches taps between uncovering and flagging when the mode button is pressed. The
+    /// touch on the button does not press the tile below it.
+    #[allow(clippy::type_complexity)]
+    fn toggle_mode(
+        localization: Res<Localization>,
+        mut mode: ResMut<TouchMode>,
+        mut press: ResMut<TouchPress>,
+        mut buttons: Query<
+            (&Interaction, &mut BackgroundColor),
+            (Changed<Interaction>, With<TouchModeButton>),
+        >,
+        mut texts: Query<&mut Text, With<TouchModeText>>,
+    ) {
+        for (interaction, mut background_color) in buttons.iter_mut() {
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+            press.pressed = None;
+            mode.toggle();
+            *background_color = Self::mode_color(*mode).into();
+            info!("Tapping now flags tiles: {}", *mode == TouchMode::Flag);
+        }
+
+        for mut text in texts.iter_mut() {
+            if mode.is_changed() || text.is_added() {
+                text.sections[0].value = Self::mode_label(*mode, &localization);
+            }
+        }
+    }
+
+    fn mode_label(mode: TouchMode, localization: &Localization) -> String {
+        match mode {
+            TouchMode::Uncover => localization.text("touch-mode-uncover"),
+            TouchMode::Flag => localization.text("touch-mode-flag"),
+        }
+    }
+
+    fn mode_color(mode: TouchMode) -> Color {
+        match mode {
+            TouchMode::Uncover => BUTTON_COLOR,
+            TouchMode::Flag => FLAG_MODE_BUTTON_COLOR,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use window_settings_plugin::WindowSettingsPlugin;
+
+#[allow(clippy::module_inception)]
+mod window_settings_plugin;
@@ -0,0 +1,253 @@
+use bevy::core::FrameCount;
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowMode, WindowMoved, WindowResized};
+use bevy::winit::WinitWindows;
+
+use crate::plugins::InputSet;
+use crate::resources::{Action, InputMap, WindowSettings};
+
+/// Fullscreen is applied once the window exists, so it covers the monitor the window was placed
+/// on rather than the primary one.
+const FRAME_COUNT_TO_RESTORE_WINDOW: u32 = 1;
+/// Frames spent windowed while moving a fullscreen window to another monitor.
+const FRAMES_TO_MOVE_FULLSCREEN_WINDOW: u32 = 2;
+/// Seconds without changes before the settings are saved, so moving or resizing the window does
+/// not write the file on every frame.
+const SAVE_DELAY: f32 = 1f32;
+
+/// Fullscreen toggle, vsync, monitor selection and remembering the window size and position. The
+/// settings are read in `main` to create the window.
+pub struct WindowSettingsPlugin;
+
+impl Plugin for WindowSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world.contains_resource::<WindowSettings>() {
+            app.insert_resource(WindowSettings::load());
+        }
+
+        app.add_systems(
+            Update,
+            (
+                Self::restore_window,
+                (Self::toggle_fullscreen, Self::cycle_monitor)
+                    .chain()
+                    .in_set(InputSet::Shortcuts),
+                Self::track_window,
+                Self::apply_window_settings,
+                Self::save_window_settings,
+            )
+                .chain(),
+        );
+
+        info!("Loaded Window Settings Plugin");
+    }
+}
+
+impl WindowSettingsPlugin {
+    /// Falls back to the primary monitor when the saved one is gone, and enters fullscreen.
+    fn restore_window(
+        frames: Res<FrameCount>,
+        winit_windows: NonSend<WinitWindows>,
+        mut window_query: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+        mut settings: ResMut<WindowSettings>,
+    ) {
+        if frames.0 != FRAME_COUNT_TO_RESTORE_WINDOW {
+            return;
+        }
+        let Ok((entity, mut window)) = window_query.get_single_mut() else {
+            return;
+        };
+
+        let monitor_count = winit_windows
+            .get_window(entity)
+            .map_or(0, |window| window.available_monitors().count());
+        if settings
+            .monitor
+            .is_some_and(|monitor| monitor >= monitor_count)
+        {
+            warn!("Saved monitor is not connected, using the primary monitor");
+            settings.monitor = None;
+            settings.position = None;
+            window.position = WindowPosition::Centered(MonitorSelection::Primary);
+        }
+
+        window.mode = settings.window_mode();
+    }
+
+    /// This system toggles fullscreen when pressing the button bound to
+    /// `Action::ToggleFullscreen` (F11 by default).
+    fn toggle_fullscreen(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        mut settings: ResMut<WindowSettings>,
+    ) {
+        if input_map.just_pressed(Action::ToggleFullscreen, &keys, &buttons) {
+            settings.fullscreen = !settings.fullscreen;
+            info!(
+                "[{}] pressed. Fullscreen is now {}",
+                input_map.label(Action::ToggleFullscreen),
+                if settings.fullscreen { "on" } else { "off" }
+            );
+        }
+    }
+
+    /// This system moves the window to the next monitor when pressing the button bound to
+    /// `Action::NextMonitor` (F10 by default).
+    fn cycle_monitor(
+        input_map: Res<InputMap>,
+        keys: Res<Input<KeyCode>>,
+        buttons: Res<Input<MouseButton>>,
+        winit_windows: NonSend<WinitWindows>,
+        window_query: Query<Entity, With<PrimaryWindow>>,
+        mut settings: ResMut<WindowSettings>,
+    ) {
+        if !input_map.just_pressed(Action::NextMonitor, &keys, &buttons) {
+            return;
+        }
+        let Some(winit_window) = window_query
+            .get_single()
+            .ok()
+            .and_then(|entity| winit_windows.get_window(entity))
+        else {
+            return;
+        };
+
+        let monitor_count = winit_window.available_monitors().count();
+        if monitor_count == 0 {
+            return;
+        }
+        let current = Self::current_monitor(winit_window).unwrap_or_default();
+        let next = (current + 1) % monitor_count;
+        info!(
+            "[{}] pressed. Moving the window to monitor {}",
+            input_map.label(Action::NextMonitor),
+            next
+        );
+        settings.monitor = Some(next);
+        settings.position = None;
+    }
+
+    /// Remembers the windowed size and position, and the monitor the window is on.
+    fn track_window(
+        winit_windows: NonSend<WinitWindows>,
+        window_query: Query<(Entity, &Window), With<PrimaryWindow>>,
+        mut settings: ResMut<WindowSettings>,
+        mut window_resized_event_reader: EventReader<WindowResized>,
+        mut window_moved_event_reader: EventReader<WindowMoved>,
+    ) {
+        let resized = window_resized_event_reader.read().last().cloned();
+        let moved = window_moved_event_reader.read().last().cloned();
+        let Ok((entity, window)) = window_query.get_single() else {
+            return;
+        };
+        if window.mode != WindowMode::Windowed {
+            return;
+        }
+
+        if let Some(event) = resized.filter(|event| event.window == entity) {
+            // Minimized windows report a zero size
+            if event.width > 0f32
+                && event.height > 0f32
+                && settings.size != (event.width, event.height)
+            {
+                settings.size = (event.width, event.height);
+            }
+        }
+
+        if let Some(event) = moved.filter(|event| event.entity == entity) {
+            let position = Some((event.position.x, event.position.y));
+            if settings.position != position {
+                settings.position = position;
+            }
+            let monitor = winit_windows
+                .get_window(entity)
+                .and_then(Self::current_monitor);
+            if monitor.is_some() && settings.monitor != monitor {
+                settings.monitor = monitor;
+            }
+        }
+    }
+
+    /// Applies changed settings to the window. A fullscreen window is briefly made windowed to
+    /// move it to another monitor.
+    fn apply_window_settings(
+        frames: Res<FrameCount>,
+        settings: Res<WindowSettings>,
+        mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+        mut applied_monitor: Local<Option<Option<usize>>>,
+        mut frames_until_fullscreen: Local<Option<u32>>,
+    ) {
+        let Ok(mut window) = window_query.get_single_mut() else {
+            return;
+        };
+        if frames.0 < FRAME_COUNT_TO_RESTORE_WINDOW {
+            return;
+        }
+
+        if let Some(frames) = frames_until_fullscreen.as_mut() {
+            *frames = frames.saturating_sub(1);
+            if *frames == 0 {
+                *frames_until_fullscreen = None;
+                window.mode = settings.window_mode();
+            }
+            return;
+        }
+
+        let applied_monitor = applied_monitor.get_or_insert(settings.monitor);
+        if !settings.is_changed() {
+            return;
+        }
+
+        let present_mode = settings.present_mode();
+        if window.present_mode != present_mode {
+            window.present_mode = present_mode;
+        }
+
+        if *applied_monitor != settings.monitor {
+            *applied_monitor = settings.monitor;
+            // Moves tracked from the window itself already have it on that monitor
+            if settings.position.is_none() {
+                window.mode = WindowMode::Windowed;
+                window.position = WindowPosition::Centered(settings.monitor_selection());
+                if settings.fullscreen {
+                    *frames_until_fullscreen = Some(FRAMES_TO_MOVE_FULLSCREEN_WINDOW);
+                }
+                return;
+            }
+        }
+
+        let mode = settings.window_mode();
+        if window.mode != mode {
+            info!("Switching window mode to {:?}", mode);
+            window.mode = mode;
+        }
+    }
+
+    fn save_window_settings(
+        time: Res<Time>,
+        settings: Res<WindowSettings>,
+        mut time_since_change: Local<Option<f32>>,
+    ) {
+        if settings.is_changed() && !settings.is_added() {
+            *time_since_change = Some(0f32);
+        }
+
+        let Some(elapsed) = time_since_change.as_mut() else {
+            return;
+        };
+        *elapsed += time.delta_seconds();
+        if *elapsed >= SAVE_DELAY {
+            *time_since_change = None;
+            info!("Saving window settings");
+            settings.save();
+        }
+    }
+
+    fn current_monitor(winit_window: &winit::window::Window) -> Option<usize> {
+        let current = winit_window.current_monitor()?;
+        winit_window
+            .available_monitors()
+            .position(|monitor| monitor == current)
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+
+const ACCESSIBILITY_SETTINGS_FILE_NAME: &str = "accessibility.ron";
+/// Pips of the dice pattern of every number, in steps from the center of the pattern.
+const DICE_PIPS: [&[(i8, i8)]; 8] = [
+    &[(0, 0)],
+    &[(-1, 1), (1, -1)],
+    &[(-1, 1), (0, 0), (1, -1)],
+    &[(-1, 1), (1, 1), (-1, -1), (1, -1)],
+    &[(-1, 1), (1, 1), (0, 0), (-1, -1), (1, -1)],
+    &[(-1, 1), (-1, 0), (-1, -1), (1, 1), (1, 0), (1, -1)],
+    &[(-1, 1), (-1, 0), (-1, -1), (0, 0), (1, 1), (1, 0), (1, -1)],
+    &[
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+        (-1, 0),
+        (1, 0),
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+    ],
+];
+
+/// Colours of the numbers. Besides the colours of the theme, every palette keeps the counts
+/// apart for one kind of colour blindness, mostly through differences in lightness.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum NumberPalette {
+    #[default]
+    Theme,
+    /// Green-blind, blues and yellows.
+    Deuteranopia,
+    /// Red-blind, blues and yellows without reds, which look dark.
+    Protanopia,
+    /// Blue-blind, reds and cyans.
+    Tritanopia,
+    /// Shades of grey, best combined with a `NumberDecoration`.
+    Monochrome,
+}
+
+impl NumberPalette {
+    pub const ALL: [NumberPalette; 5] = [
+        NumberPalette::Theme,
+        NumberPalette::Deuteranopia,
+        NumberPalette::Protanopia,
+        NumberPalette::Tritanopia,
+        NumberPalette::Monochrome,
+    ];
+
+    /// Built-in colours of the numbers 1 to 8, light ones for dark tiles and dark ones for
+    /// light tiles. `None` for the theme palette.
+    pub fn colors(&self, dark_tiles: bool) -> Option<[&'static str; 8]> {
+        let (light, dark) = match self {
+            Self::Theme => return None,
+            Self::Deuteranopia => (
+                [
+                    "5AA9FF", "FFB000", "FFFFFF", "B8D8FF", "FFE66D", "8C8CFF", "C98A00", "9E9E9E",
+                ],
+                [
+                    "0050C8", "A35F00", "000000", "00287A", "7A6A00", "6A4FC8", "5C3A00", "757575",
+                ],
+            ),
+            Self::Protanopia => (
+                [
+                    "4FA3FF", "F0E442", "FFFFFF", "A8C8FF", "D4B000", "7F7FFF", "E0E0A0", "9E9E9E",
+                ],
+                [
+                    "0050C8", "8C7A00", "000000", "00287A", "5C5000", "5A4FC8", "3A3A8C", "757575",
+                ],
+            ),
+            Self::Tritanopia => (
+                [
+                    "FF6B6B", "3FD4D4", "FFFFFF", "FF9FD0", "A8F0F0", "E03030", "C060A0", "9E9E9E",
+                ],
+                [
+                    "D00000", "007A7A", "000000", "B0307A", "004C4C", "7A0000", "E06090", "757575",
+                ],
+            ),
+            Self::Monochrome => (
+                [
+                    "FFFFFF", "EBEBEB", "D6D6D6", "C2C2C2", "ADADAD", "999999", "858585", "707070",
+                ],
+                [
+                    "000000", "141414", "292929", "3D3D3D", "525252", "666666", "7A7A7A", "8F8F8F",
+                ],
+            ),
+        };
+        Some(if dark_tiles { light } else { dark })
+    }
+}
+
+/// Decoration drawn in the corner of every number, so telling the counts apart does not depend
+/// on their colour.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum NumberDecoration {
+    #[default]
+    None,
+    /// As many pips as the number, laid out like on a dice.
+    Dice,
+    /// A glyph of the theme for every number, see `ThemeDefinition::number_glyphs`.
+    Glyphs,
+}
+
+impl NumberDecoration {
+    pub const ALL: [NumberDecoration; 3] = [
+        NumberDecoration::None,
+        NumberDecoration::Dice,
+        NumberDecoration::Glyphs,
+    ];
+
+    /// Pips of the dice pattern of a number, as steps to the left or right and up or down from
+    /// the center of the pattern.
+    pub fn dice_pips(count: u8) -> &'static [(i8, i8)] {
+        DICE_PIPS[(count.clamp(1, DICE_PIPS.len() as u8) - 1) as usize]
+    }
+}
+
+/// Options for players who can not rely on colours, persisted between sessions.
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    /// Replaces the number colours of the theme, see `ThemeDefinition::board_assets`.
+    pub number_palette: NumberPalette,
+    pub number_decoration: NumberDecoration,
+}
+
+impl AccessibilitySettings {
+    pub fn load() -> Self {
+        persistence::load(ACCESSIBILITY_SETTINGS_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        persistence::save(ACCESSIBILITY_SETTINGS_FILE_NAME, self);
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+
+const ANIMATION_SETTINGS_FILE_NAME: &str = "animations.ron";
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum UncoverAnimation {
+    #[default]
+    Fade,
+    Flip,
+}
+
+/// Controls the purely visual tile animations. Game logic never waits for them. Persisted
+/// between sessions, except for the command line overrides.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct AnimationSettings {
+    /// Chosen by the player, see `is_enabled` for whether animations play.
+    pub enabled: bool,
+    /// Multiplier applied to every animation, `2.0` plays them twice as fast.
+    pub speed: f32,
+    pub uncover: UncoverAnimation,
+    /// Set by `--no-animations` for this session only.
+    #[serde(skip)]
+    pub disabled_by_args: bool,
+    /// Set by `--animation-speed` for this session only, replaces `speed`.
+    #[serde(skip)]
+    pub speed_from_args: Option<f32>,
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            speed: 1f32,
+            uncover: UncoverAnimation::default(),
+            disabled_by_args: false,
+            speed_from_args: None,
+        }
+    }
+}
+
+impl AnimationSettings {
+    pub fn load() -> Self {
+        persistence::load(ANIMATION_SETTINGS_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        persistence::save(ANIMATION_SETTINGS_FILE_NAME, self);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled && !self.disabled_by_args
+    }
+
+    /// Switches the animations the player sees, which also lifts `--no-animations`.
+    pub fn toggle(&mut self) {
+        self.enabled = !self.is_enabled();
+        self.disabled_by_args = false;
+    }
+
+    /// Scales a duration in seconds by the animation speed.
+    pub fn scaled(&self, seconds: f32) -> f32 {
+        seconds / self.speed_from_args.unwrap_or(self.speed).max(0.01)
+    }
+}
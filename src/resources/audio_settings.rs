@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+
+const AUDIO_SETTINGS_FILE_NAME: &str = "audio.ron";
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct AudioChannel {
+    pub volume: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioChannel {
+    fn default() -> Self {
+        Self {
+            volume: 1f32,
+            muted: false,
+        }
+    }
+}
+
+impl AudioChannel {
+    fn level(&self) -> f32 {
+        if self.muted {
+            0f32
+        } else {
+            self.volume.clamp(0f32, 1f32)
+        }
+    }
+}
+
+/// Volume of the sound effects and the music, scaled by the master channel. Persisted between
+/// sessions.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct AudioSettings {
+    pub master: AudioChannel,
+    pub effects: AudioChannel,
+    pub music: AudioChannel,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master: AudioChannel::default(),
+            effects: AudioChannel::default(),
+            music: AudioChannel {
+                volume: 0.5,
+                muted: false,
+            },
+        }
+    }
+}
+
+impl AudioSettings {
+    pub fn load() -> Self {
+        persistence::load(AUDIO_SETTINGS_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        persistence::save(AUDIO_SETTINGS_FILE_NAME, self);
+    }
+
+    pub fn effects_volume(&self) -> f32 {
+        self.master.level() * self.effects.level()
+    }
+
+    pub fn music_volume(&self) -> f32 {
+        self.master.level() * self.music.level()
+    }
+}
@@ -5,6 +5,14 @@ use crate::components::Coordinates;
 use crate::plugins::Bounds2;
 use crate::resources::TileMap;
 
+/// Mark of a covered tile, see `Board::try_toggle_mark`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TileMark {
+    Unmarked,
+    Flag,
+    QuestionMark,
+}
+
 #[derive(Debug, Resource)]
 pub struct Board {
     pub tile_map: TileMap,
@@ -13,23 +21,44 @@ pub struct Board {
     pub covered_tiles: HashMap<Coordinates, Entity>,
     pub entity: Entity,
     pub marked_tiles: Vec<Coordinates>,
+    /// Tiles marked as unsure. They are not flags: they can be uncovered and do not count when
+    /// chording.
+    pub question_marks: Vec<Coordinates>,
 }
 
 impl Board {
-    pub fn mouse_position(&self, window: &Window, position: Vec2) -> Option<Coordinates> {
-        let window_size = Vec2::new(window.width(), window.height());
-        let position = position - window_size / 2f32;
+    /// Tile under a cursor position in window coordinates, seen through the given camera.
+    pub fn mouse_position(
+        &self,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        position: Vec2,
+    ) -> Option<Coordinates> {
+        let position = camera.viewport_to_world_2d(camera_transform, position)?;
 
         if !self.bounds.is_in_bounds(position) {
             return None;
         }
 
-        let coordinates = position - self.bounds.position;
-        Some(Coordinates {
-            x: (coordinates.x / self.tile_size) as u16,
-            // https://bevyengine.org/learn/migration-guides/0.10-0.11/#consistent-screen-space-coordinates
-            y: self.tile_map.height() - 1 - (coordinates.y / self.tile_size) as u16,
-        })
+        let coordinates = (position - self.bounds.position) / self.tile_size;
+        let coordinates = Coordinates {
+            x: coordinates.x as u16,
+            y: coordinates.y as u16,
+        };
+        // The far edges are part of the bounds but not of any tile
+        if coordinates.x >= self.tile_map.width() || coordinates.y >= self.tile_map.height() {
+            return None;
+        }
+        Some(coordinates)
+    }
+
+    /// World position of the center of a tile.
+    pub fn tile_center(&self, coordinates: Coordinates) -> Vec2 {
+        self.bounds.position
+            + Vec2::new(
+                (coordinates.x as f32 + 0.5) * self.tile_size,
+                (coordinates.y as f32 + 0.5) * self.tile_size,
+            )
     }
 
     pub fn tile_to_uncover(&self, coordinates: &Coordinates) -> Option<&Entity> {
@@ -44,6 +73,7 @@ impl Board {
         if self.marked_tiles.contains(coordinates) {
             self.unmark_tile(coordinates)?;
         }
+        self.question_marks.retain(|coords| coords != coordinates);
 
         self.covered_tiles.remove(coordinates)
     }
@@ -56,6 +86,39 @@ impl Board {
             .collect()
     }
 
+    /// Covers to uncover when chording on an uncovered number: once as many neighbours are
+    /// flagged as the number says, the other covered neighbours are uncovered.
+    pub fn chord_tiles(&self, coordinates: Coordinates) -> Vec<Entity> {
+        if self.covered_tiles.contains_key(&coordinates) || !self.tile_map.has_tile_at(coordinates)
+        {
+            return Vec::new();
+        }
+        let count = self.tile_map.mine_count_at(coordinates) as usize;
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let neighbours: Vec<Coordinates> = self
+            .tile_map
+            .safe_square_at(coordinates)
+            .filter(|coords| self.covered_tiles.contains_key(coords))
+            .collect();
+        let flagged = neighbours
+            .iter()
+            .filter(|coords| self.marked_tiles.contains(coords))
+            .count();
+        if flagged != count {
+            return Vec::new();
+        }
+
+        neighbours
+            .iter()
+            .filter(|coords| !self.marked_tiles.contains(coords))
+            .filter_map(|coords| self.covered_tiles.get(coords))
+            .copied()
+            .collect()
+    }
+
     fn unmark_tile(&mut self, coords: &Coordinates) -> Option<Coordinates> {
         let pos = match self.marked_tiles.iter().position(|a| a == coords) {
             None => {
@@ -71,14 +134,29 @@ impl Board {
         self.tile_map.mine_count() as usize == self.covered_tiles.len()
     }
 
-    pub fn try_toggle_mark(&mut self, coords: &Coordinates) -> Option<(Entity, bool)> {
+    /// Cycles the mark of a covered tile and returns its cover along with the new mark. With
+    /// question marks the cycle is flag, question mark, unmarked.
+    pub fn try_toggle_mark(
+        &mut self,
+        coords: &Coordinates,
+        question_marks: bool,
+    ) -> Option<(Entity, TileMark)> {
         let entity = *self.covered_tiles.get(coords)?;
         let mark = if self.marked_tiles.contains(coords) {
             self.unmark_tile(coords)?;
-            false
+            if question_marks {
+                self.question_marks.push(*coords);
+                TileMark::QuestionMark
+            } else {
+                TileMark::Unmarked
+            }
+        } else if self.question_marks.contains(coords) {
+            self.question_marks
+                .retain(|coordinates| coordinates != coords);
+            TileMark::Unmarked
         } else {
             self.marked_tiles.push(*coords);
-            true
+            TileMark::Flag
         };
         Some((entity, mark))
     }
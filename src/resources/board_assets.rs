@@ -6,6 +6,31 @@ pub struct SpriteMaterial {
     pub texture: Handle<Image>,
 }
 
+/// Colors and strength of the particle effects and camera shake.
+#[derive(Debug, Clone)]
+pub struct EffectAssets {
+    pub debris_colors: Vec<Color>,
+    pub smoke_color: Color,
+    pub confetti_colors: Vec<Color>,
+    /// Multiplier for the number of particles spawned, `0` disables particles.
+    pub particle_density: f32,
+    /// Maximum camera offset in pixels when a mine explodes, `0` disables the shake.
+    pub camera_shake: f32,
+}
+
+/// Sound files overriding the built-in sound effects, and the optional background music.
+#[derive(Debug, Clone, Default)]
+pub struct SoundAssets {
+    pub uncover: Option<Handle<AudioSource>>,
+    pub cascade: Option<Handle<AudioSource>>,
+    pub flag: Option<Handle<AudioSource>>,
+    pub unflag: Option<Handle<AudioSource>>,
+    pub chord: Option<Handle<AudioSource>>,
+    pub explosion: Option<Handle<AudioSource>>,
+    pub win: Option<Handle<AudioSource>>,
+    pub music: Option<Handle<AudioSource>>,
+}
+
 #[derive(Debug, Clone, Resource)]
 pub struct BoardAssets {
     pub label: String,
@@ -14,22 +39,15 @@ pub struct BoardAssets {
     pub covered_tile_material: SpriteMaterial,
     pub mine_counter_font: Handle<Font>,
     pub mine_counter_colors: Vec<Color>,
+    pub number_glyphs: Vec<String>,
+    pub number_glyph_font: Handle<Font>,
     pub flag_material: SpriteMaterial,
     pub mine_material: SpriteMaterial,
+    pub effects: EffectAssets,
+    pub sounds: SoundAssets,
 }
 
 impl BoardAssets {
-    pub fn default_colors() -> Vec<Color> {
-        vec![
-            Color::WHITE,
-            Color::GREEN,
-            Color::YELLOW,
-            Color::ORANGE,
-            Color::RED,
-            Color::PURPLE,
-        ]
-    }
-
     pub fn mine_counter_color(&self, counter: u8) -> Color {
         let color_idx = counter.saturating_sub(1) as usize;
         match self.mine_counter_colors.get(color_idx) {
@@ -40,4 +58,18 @@ impl BoardAssets {
             },
         }
     }
+
+    /// Glyph decorating a number, the last one is reused for higher numbers.
+    pub fn number_glyph(&self, counter: u8) -> &str {
+        let glyph_idx = counter.saturating_sub(1) as usize;
+        self.number_glyphs
+            .get(glyph_idx)
+            .or(self.number_glyphs.last())
+            .map_or("", String::as_str)
+    }
+
+    /// Question marks are drawn in the board colour, which contrasts with the covers.
+    pub fn question_mark_color(&self) -> Color {
+        self.board_material.color
+    }
 }
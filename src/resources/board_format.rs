@@ -0,0 +1,314 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use bevy::utils::HashSet;
+
+use crate::components::Coordinates;
+use crate::resources::{BoardMask, BoardMaskError, TileMap};
+
+#[derive(Debug)]
+pub enum BoardFormatError {
+    Io(std::io::Error),
+    UnknownFormat,
+    InvalidCharacter { character: char, line: usize },
+    UnexpectedEof,
+    MineOutOfBounds(Coordinates),
+    DuplicateMine(Coordinates),
+    Mask(BoardMaskError),
+    UnsupportedBoard(&'static str),
+}
+
+impl Display for BoardFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to access board file: {}", e),
+            Self::UnknownFormat => write!(f, "unknown board file extension"),
+            Self::InvalidCharacter { character, line } => {
+                write!(f, "invalid character '{}' on line {}", character, line)
+            }
+            Self::UnexpectedEof => write!(f, "board data ended unexpectedly"),
+            Self::MineOutOfBounds(c) => write!(f, "mine {} lies outside of the board", c),
+            Self::DuplicateMine(c) => write!(f, "mine {} is defined more than once", c),
+            Self::Mask(e) => write!(f, "invalid board shape: {}", e),
+            Self::UnsupportedBoard(reason) => write!(f, "board cannot be written: {}", reason),
+        }
+    }
+}
+
+impl From<std::io::Error> for BoardFormatError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<BoardMaskError> for BoardFormatError {
+    fn from(e: BoardMaskError) -> Self {
+        Self::Mask(e)
+    }
+}
+
+/// Board layout file formats that can be converted to and from a `TileMap`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BoardFormat {
+    /// Plain text grid, one line per row with the top row first.
+    /// `*` is a mine, `.` (or a digit) is a safe tile and a space is a hole in shaped boards.
+    /// Shorter lines are padded with holes and empty lines are rows of holes.
+    Text,
+    /// Minesweeper X board format (`.mbf`): width and height as single bytes, the mine count as
+    /// a big-endian `u16`, followed by `(x, y)` byte pairs with the origin in the top left corner.
+    Mbf,
+}
+
+impl BoardFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "txt" | "board" => Some(Self::Text),
+            "mbf" => Some(Self::Mbf),
+            _ => None,
+        }
+    }
+
+    pub fn read(&self, data: &[u8]) -> Result<TileMap, BoardFormatError> {
+        match self {
+            Self::Text => Self::read_text(&String::from_utf8_lossy(data)),
+            Self::Mbf => Self::read_mbf(data),
+        }
+    }
+
+    pub fn write(&self, tile_map: &TileMap) -> Result<Vec<u8>, BoardFormatError> {
+        match self {
+            Self::Text => Ok(Self::write_text(tile_map).into_bytes()),
+            Self::Mbf => Self::write_mbf(tile_map),
+        }
+    }
+
+    /// Loads a board, picking the format from the file extension.
+    pub fn load(path: &Path) -> Result<TileMap, BoardFormatError> {
+        let format = Self::from_path(path).ok_or(BoardFormatError::UnknownFormat)?;
+        format.read(&std::fs::read(path)?)
+    }
+
+    /// Saves a board, picking the format from the file extension.
+    pub fn save(path: &Path, tile_map: &TileMap) -> Result<(), BoardFormatError> {
+        let format = Self::from_path(path).ok_or(BoardFormatError::UnknownFormat)?;
+        std::fs::write(path, format.write(tile_map)?)?;
+        Ok(())
+    }
+
+    fn read_text(text: &str) -> Result<TileMap, BoardFormatError> {
+        let mut shape = String::new();
+        let mut mine_rows = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            // Empty lines are kept, they are rows of holes
+            let line = line.trim_end_matches(['\r', '\n']);
+            let mut mines = Vec::new();
+            for (x, character) in line.chars().enumerate() {
+                match character {
+                    '*' | 'x' | 'X' => {
+                        shape.push('#');
+                        mines.push(x as u16);
+                    }
+                    '.' | '0'..='8' => shape.push('#'),
+                    ' ' => shape.push('.'),
+                    _ => {
+                        return Err(BoardFormatError::InvalidCharacter {
+                            character,
+                            line: index + 1,
+                        })
+                    }
+                }
+            }
+
+            shape.push('\n');
+            mine_rows.push(mines);
+        }
+
+        let mask = BoardMask::from_text(&shape)?;
+        let height = mask.height();
+        let mines: Vec<Coordinates> = mine_rows
+            .into_iter()
+            .enumerate()
+            .flat_map(|(row, xs)| {
+                let y = height - 1 - row as u16;
+                xs.into_iter().map(move |x| Coordinates { x, y })
+            })
+            .collect();
+
+        Self::build_tile_map(mask, &mines)
+    }
+
+    fn write_text(tile_map: &TileMap) -> String {
+        let mut text = String::new();
+        for y in (0..tile_map.height()).rev() {
+            let line: String = (0..tile_map.width())
+                .map(|x| {
+                    let coordinates = Coordinates { x, y };
+                    if !tile_map.has_tile_at(coordinates) {
+                        ' '
+                    } else if tile_map.is_mine_at(coordinates) {
+                        '*'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            // Not trimmed, so rows and columns of holes on the edges are kept
+            text.push_str(&line);
+            text.push('\n');
+        }
+        text
+    }
+
+    fn read_mbf(data: &[u8]) -> Result<TileMap, BoardFormatError> {
+        let [width, height, count_high, count_low, mines @ ..] = data else {
+            return Err(BoardFormatError::UnexpectedEof);
+        };
+
+        let (width, height) = (*width as u16, *height as u16);
+        if width == 0 || height == 0 {
+            return Err(BoardFormatError::Mask(BoardMaskError::Empty));
+        }
+        let count = u16::from_be_bytes([*count_high, *count_low]) as usize;
+        if mines.len() < count * 2 {
+            return Err(BoardFormatError::UnexpectedEof);
+        }
+
+        let mines: Vec<Coordinates> = mines
+            .chunks_exact(2)
+            .take(count)
+            .map(|pair| Coordinates {
+                x: pair[0] as u16,
+                // MBF rows start at the top, the tile map rows start at the bottom
+                y: height.wrapping_sub(1).wrapping_sub(pair[1] as u16),
+            })
+            .collect();
+
+        Self::build_tile_map(BoardMask::rectangle(width, height), &mines)
+    }
+
+    fn build_tile_map(mask: BoardMask, mines: &[Coordinates]) -> Result<TileMap, BoardFormatError> {
+        let mut seen = HashSet::with_capacity(mines.len());
+        for mine in mines {
+            if !mask.contains(*mine) {
+                return Err(BoardFormatError::MineOutOfBounds(*mine));
+            }
+            if !seen.insert(*mine) {
+                return Err(BoardFormatError::DuplicateMine(*mine));
+            }
+        }
+
+        Ok(TileMap::from_mines(mask, mines))
+    }
+
+    fn write_mbf(tile_map: &TileMap) -> Result<Vec<u8>, BoardFormatError> {
+        if !tile_map.is_rectangle() {
+            return Err(BoardFormatError::UnsupportedBoard(
+                "MBF only supports rectangular boards",
+            ));
+        }
+
+        let (width, height) = (tile_map.width(), tile_map.height());
+        if width > u8::MAX as u16 || height > u8::MAX as u16 {
+            return Err(BoardFormatError::UnsupportedBoard(
+                "MBF boards are limited to 255x255 tiles",
+            ));
+        }
+
+        let mines: Vec<Coordinates> = tile_map.mines().collect();
+        let mut data = Vec::with_capacity(4 + mines.len() * 2);
+        data.push(width as u8);
+        data.push(height as u8);
+        data.extend_from_slice(&(mines.len() as u16).to_be_bytes());
+        for mine in mines {
+            data.push(mine.x as u8);
+            data.push((height - 1 - mine.y) as u8);
+        }
+
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mines(tile_map: &TileMap) -> HashSet<Coordinates> {
+        tile_map.mines().collect()
+    }
+
+    fn assert_same_board(expected: &TileMap, actual: &TileMap) {
+        assert_eq!(
+            (expected.width(), expected.height()),
+            (actual.width(), actual.height())
+        );
+        for y in 0..expected.height() {
+            for x in 0..expected.width() {
+                let coordinates = Coordinates { x, y };
+                assert_eq!(
+                    expected.has_tile_at(coordinates),
+                    actual.has_tile_at(coordinates),
+                    "tile {}",
+                    coordinates
+                );
+            }
+        }
+        assert_eq!(mines(expected), mines(actual));
+    }
+
+    #[test]
+    fn text_round_trip_keeps_rows_of_holes() {
+        let mask = BoardMask::heart(20);
+        let mine_positions: Vec<Coordinates> = (0..20)
+            .flat_map(|y| (0..20).map(move |x| Coordinates { x, y }))
+            .filter(|coordinates| mask.contains(*coordinates))
+            .step_by(7)
+            .collect();
+        let tile_map = TileMap::from_mines(mask, &mine_positions);
+
+        let data = BoardFormat::Text.write(&tile_map).unwrap();
+        let read = BoardFormat::Text.read(&data).unwrap();
+
+        assert_same_board(&tile_map, &read);
+    }
+
+    #[test]
+    fn text_keeps_empty_rows_in_the_middle() {
+        let read = BoardFormat::Text.read(b"*..\n\n..*\n").unwrap();
+
+        assert_eq!((read.width(), read.height()), (3, 3));
+        assert!(!read.has_tile_at(Coordinates { x: 0, y: 1 }));
+        assert_eq!(
+            mines(&read),
+            HashSet::from_iter([Coordinates { x: 0, y: 2 }, Coordinates { x: 2, y: 0 }])
+        );
+    }
+
+    #[test]
+    fn text_without_tiles_is_rejected() {
+        assert!(BoardFormat::Text.read(b"").is_err());
+        assert!(BoardFormat::Text.read(b"\n\n").is_err());
+    }
+
+    #[test]
+    fn mbf_round_trip() {
+        let mine_positions = [
+            Coordinates { x: 0, y: 0 },
+            Coordinates { x: 7, y: 2 },
+            Coordinates { x: 3, y: 4 },
+        ];
+        let tile_map = TileMap::from_mines(BoardMask::rectangle(8, 5), &mine_positions);
+
+        let data = BoardFormat::Mbf.write(&tile_map).unwrap();
+        let read = BoardFormat::Mbf.read(&data).unwrap();
+
+        assert_same_board(&tile_map, &read);
+    }
+
+    #[test]
+    fn mbf_without_tiles_is_rejected() {
+        assert!(BoardFormat::Mbf.read(&[0, 5, 0, 0]).is_err());
+        assert!(BoardFormat::Mbf.read(&[5, 0, 0, 0]).is_err());
+    }
+}
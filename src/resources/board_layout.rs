@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::components::Coordinates;
+use crate::resources::TileMap;
+
+/// A predefined mine layout. When present, boards are created from it instead of placing mines
+/// randomly, with the `revealed` and `flagged` tiles already uncovered and marked.
+#[derive(Debug, Clone, Resource)]
+pub struct BoardLayout {
+    pub tile_map: TileMap,
+    pub revealed: Vec<Coordinates>,
+    pub flagged: Vec<Coordinates>,
+}
+
+impl BoardLayout {
+    pub fn new(tile_map: TileMap) -> Self {
+        Self {
+            tile_map,
+            revealed: Vec::new(),
+            flagged: Vec::new(),
+        }
+    }
+
+    pub fn has_initial_state(&self) -> bool {
+        !self.revealed.is_empty() || !self.flagged.is_empty()
+    }
+}
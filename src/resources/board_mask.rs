@@ -0,0 +1,239 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use crate::components::Coordinates;
+
+/// Minimum alpha value for a pixel of a mask image to be treated as an existing tile.
+const IMAGE_ALPHA_THRESHOLD: u8 = 128;
+
+#[derive(Debug)]
+pub enum BoardMaskError {
+    Io(std::io::Error),
+    Image(image::ImageError),
+    Empty,
+    TooLarge,
+}
+
+impl Display for BoardMaskError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read mask file: {}", e),
+            Self::Image(e) => write!(f, "failed to decode mask image: {}", e),
+            Self::Empty => write!(f, "mask does not contain any tiles"),
+            Self::TooLarge => write!(f, "mask dimensions exceed {}x{}", u16::MAX, u16::MAX),
+        }
+    }
+}
+
+impl From<std::io::Error> for BoardMaskError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<image::ImageError> for BoardMaskError {
+    fn from(e: image::ImageError) -> Self {
+        Self::Image(e)
+    }
+}
+
+/// Describes the shape of a board. Cells set to `false` are holes: they have no tile, are never
+/// mined and are not taken into account when counting neighbors.
+///
+/// Rows are stored bottom to top, the same way as in the `TileMap`.
+#[derive(Debug, Clone, Eq, PartialEq, Resource)]
+pub struct BoardMask {
+    width: u16,
+    height: u16,
+    cells: Vec<Vec<bool>>,
+}
+
+impl BoardMask {
+    pub fn rectangle(width: u16, height: u16) -> Self {
+        Self::from_fn(width, height, |_| true)
+    }
+
+    pub fn circle(diameter: u16) -> Self {
+        let radius = diameter as f32 / 2f32;
+        Self::from_fn(diameter, diameter, |coordinates| {
+            let center = Self::cell_center(coordinates) - Vec2::splat(radius);
+            center.length() <= radius
+        })
+    }
+
+    pub fn heart(size: u16) -> Self {
+        let scale = size as f32 / 2f32;
+        Self::from_fn(size, size, |coordinates| {
+            // Implicit heart curve: (x^2 + y^2 - 1)^3 - x^2 * y^3 <= 0, for x and y in [-1.3, 1.3]
+            let p = (Self::cell_center(coordinates) - Vec2::splat(scale)) / scale * 1.3f32;
+            let a = p.x * p.x + p.y * p.y - 1f32;
+            a * a * a - p.x * p.x * p.y * p.y * p.y <= 0f32
+        })
+    }
+
+    /// Parses a text mask. Every line is a row, with the first line being the top of the board.
+    /// `.` and spaces are holes, any other character is a tile. Shorter lines are padded with holes,
+    /// empty lines are rows of holes.
+    pub fn from_text(text: &str) -> Result<Self, BoardMaskError> {
+        let rows: Vec<Vec<bool>> = text
+            .lines()
+            .map(|line| line.trim_end())
+            .map(|line| line.chars().map(|c| c != '.' && c != ' ').collect())
+            .collect();
+
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let height = rows.len();
+        if width > u16::MAX as usize || height > u16::MAX as usize {
+            return Err(BoardMaskError::TooLarge);
+        }
+
+        let cells = rows
+            .into_iter()
+            .rev()
+            .map(|mut row| {
+                row.resize(width, false);
+                row
+            })
+            .collect();
+
+        Self::new(width as u16, height as u16, cells)
+    }
+
+    /// Builds a mask from an image. Opaque pixels are tiles, transparent pixels are holes.
+    /// One pixel corresponds to one tile.
+    pub fn from_image(image: &image::DynamicImage) -> Result<Self, BoardMaskError> {
+        let image = image.to_rgba8();
+        let (width, height) = image.dimensions();
+        if width > u16::MAX as u32 || height > u16::MAX as u32 {
+            return Err(BoardMaskError::TooLarge);
+        }
+
+        let cells = (0..height)
+            .rev()
+            .map(|y| {
+                (0..width)
+                    .map(|x| image.get_pixel(x, y)[3] >= IMAGE_ALPHA_THRESHOLD)
+                    .collect()
+            })
+            .collect();
+
+        Self::new(width as u16, height as u16, cells)
+    }
+
+    /// Loads a mask from a `.png` image or a text file, based on the file extension.
+    pub fn load(path: &Path) -> Result<Self, BoardMaskError> {
+        let is_png = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("png"));
+
+        if is_png {
+            Self::from_image(&image::open(path)?)
+        } else {
+            Self::from_text(&std::fs::read_to_string(path)?)
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn contains(&self, coordinates: Coordinates) -> bool {
+        if coordinates.x >= self.width || coordinates.y >= self.height {
+            return false;
+        }
+
+        self.cells[coordinates.y as usize][coordinates.x as usize]
+    }
+
+    pub fn tile_count(&self) -> usize {
+        self.cells.iter().flatten().filter(|cell| **cell).count()
+    }
+
+    pub fn is_rectangle(&self) -> bool {
+        self.cells.iter().flatten().all(|cell| *cell)
+    }
+
+    fn new(width: u16, height: u16, cells: Vec<Vec<bool>>) -> Result<Self, BoardMaskError> {
+        let mask = Self {
+            width,
+            height,
+            cells,
+        };
+
+        if mask.tile_count() == 0 {
+            return Err(BoardMaskError::Empty);
+        }
+
+        Ok(mask)
+    }
+
+    fn from_fn(width: u16, height: u16, f: impl Fn(Coordinates) -> bool) -> Self {
+        let cells = (0..height)
+            .map(|y| (0..width).map(|x| f(Coordinates { x, y })).collect())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn cell_center(coordinates: Coordinates) -> Vec2 {
+        Vec2::new(coordinates.x as f32, coordinates.y as f32) + Vec2::splat(0.5f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles(mask: &BoardMask) -> Vec<(u16, u16)> {
+        (0..mask.height())
+            .flat_map(|y| (0..mask.width()).map(move |x| Coordinates { x, y }))
+            .filter(|coordinates| mask.contains(*coordinates))
+            .map(|coordinates| (coordinates.x, coordinates.y))
+            .collect()
+    }
+
+    #[test]
+    fn text_holes_are_dots_and_spaces() {
+        let mask = BoardMask::from_text("#.#\n# #\n###").unwrap();
+
+        assert_eq!((mask.width(), mask.height()), (3, 3));
+        assert_eq!(
+            tiles(&mask),
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1), (0, 2), (2, 2)]
+        );
+        assert!(!mask.is_rectangle());
+    }
+
+    #[test]
+    fn text_ragged_rows_are_padded_with_holes() {
+        let mask = BoardMask::from_text("##\n\n####").unwrap();
+
+        assert_eq!((mask.width(), mask.height()), (4, 3));
+        assert_eq!(
+            tiles(&mask),
+            vec![(0, 0), (1, 0), (2, 0), (3, 0), (0, 2), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn text_without_tiles_is_empty() {
+        assert!(matches!(
+            BoardMask::from_text(""),
+            Err(BoardMaskError::Empty)
+        ));
+        assert!(matches!(
+            BoardMask::from_text(". .\n\n..."),
+            Err(BoardMaskError::Empty)
+        ));
+    }
+}
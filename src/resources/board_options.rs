@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::persistence;
+
+const BOARD_OPTIONS_FILE_NAME: &str = "board.ron";
+
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub enum TileSize {
     Fixed(f32),
@@ -13,7 +17,10 @@ pub enum BoardPosition {
     CustomPosition(Vec3),
 }
 
+/// Options of newly generated boards, persisted between sessions. The tile size and padding
+/// also apply to the current board.
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Resource)]
+#[serde(default)]
 pub struct BoardOptions {
     pub map_size: (u16, u16),
     pub mine_count: u16,
@@ -41,12 +48,22 @@ impl Default for BoardPosition {
 impl Default for BoardOptions {
     fn default() -> Self {
         Self {
-            map_size: (15, 15),
-            mine_count: 30,
+            map_size: (20, 20),
+            mine_count: 60,
             position: Default::default(),
             tile_size: Default::default(),
-            tile_padding: 0f32,
-            safe_start_enabled: false,
+            tile_padding: 3f32,
+            safe_start_enabled: true,
         }
     }
 }
+
+impl BoardOptions {
+    pub fn load() -> Self {
+        persistence::load(BOARD_OPTIONS_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        persistence::save(BOARD_OPTIONS_FILE_NAME, self);
+    }
+}
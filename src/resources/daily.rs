@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use chrono::{Datelike, Local, NaiveDate};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::components::Coordinates;
+use crate::persistence;
+use crate::resources::{BoardLayout, BoardMask, TileMap};
+
+const HISTORY_FILE_NAME: &str = "daily_history.ron";
+const MIN_SIZE: u16 = 12;
+const MAX_SIZE: u16 = 24;
+/// Mine density in mines per thousand tiles, an integer so the mine count needs no rounding.
+const MIN_DENSITY: u32 = 120;
+const MAX_DENSITY: u32 = 200;
+
+/// The daily challenge currently being played. Board dimensions, mine density and mine positions
+/// are all derived from the date, so everyone gets the same board on the same day.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Resource)]
+pub struct DailyChallenge {
+    pub date: NaiveDate,
+}
+
+impl DailyChallenge {
+    pub fn today() -> Self {
+        Self {
+            date: Local::now().date_naive(),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        let date = self.date.year() as u64 * 10_000 + self.date.month() as u64 * 100;
+        date + self.date.day() as u64
+    }
+
+    /// Everything is derived from the raw output of a generator with a fixed algorithm using
+    /// explicit arithmetic, since the sampling of `Rng::gen_range` may change between versions
+    /// of `rand`. `layout_is_pinned_to_the_date` catches any change when upgrading.
+    pub fn layout(&self) -> BoardLayout {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed());
+        let width = Self::next_in_range(&mut rng, MIN_SIZE as u32, MAX_SIZE as u32);
+        let height = Self::next_in_range(&mut rng, MIN_SIZE as u32, MAX_SIZE as u32);
+        let density = Self::next_in_range(&mut rng, MIN_DENSITY, MAX_DENSITY);
+        let tile_count = width * height;
+        let mine_count = ((tile_count * density + 500) / 1000) as usize;
+
+        let mut mines: Vec<Coordinates> = Vec::with_capacity(mine_count);
+        while mines.len() < mine_count {
+            let index = rng.next_u32() % tile_count;
+            let coordinates = Coordinates {
+                x: (index % width) as u16,
+                y: (index / width) as u16,
+            };
+            if !mines.contains(&coordinates) {
+                mines.push(coordinates);
+            }
+        }
+
+        let mask = BoardMask::rectangle(width as u16, height as u16);
+        BoardLayout::new(TileMap::from_mines(mask, &mines))
+    }
+
+    /// Value in `min..=max`. The modulo bias is negligible for ranges this small.
+    fn next_in_range(rng: &mut ChaCha8Rng, min: u32, max: u32) -> u32 {
+        min + rng.next_u32() % (max - min + 1)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DailyResult {
+    /// The board was started but neither won nor lost, e.g. because the player left it.
+    Unfinished,
+    Lost,
+    Won {
+        seconds: f32,
+    },
+}
+
+/// Results of past daily challenges, persisted between sessions.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Resource)]
+pub struct DailyHistory {
+    results: BTreeMap<NaiveDate, DailyResult>,
+}
+
+impl DailyHistory {
+    pub fn load() -> Self {
+        persistence::load(HISTORY_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn result(&self, date: &NaiveDate) -> Option<DailyResult> {
+        self.results.get(date).copied()
+    }
+
+    pub fn is_attempted(&self, date: &NaiveDate) -> bool {
+        self.results.contains_key(date)
+    }
+
+    pub fn record(&mut self, date: NaiveDate, result: DailyResult) {
+        self.results.insert(date, result);
+        persistence::save(HISTORY_FILE_NAME, self);
+    }
+}
+
+/// First day of the month displayed in the daily challenge calendar.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Resource)]
+pub struct DailyCalendar {
+    pub month: NaiveDate,
+}
+
+impl DailyCalendar {
+    pub fn current() -> Self {
+        let today = Local::now().date_naive();
+        Self {
+            month: today.with_day(1).unwrap_or(today),
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if let Some(month) = self.month.pred_opt().and_then(|d| d.with_day(1)) {
+            self.month = month;
+        }
+    }
+
+    pub fn next(&mut self) {
+        if let Some(month) = self.month.checked_add_months(chrono::Months::new(1)) {
+            self.month = month;
+        }
+    }
+
+    /// Days of the displayed month, in order.
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.month
+            .iter_days()
+            .take_while(|day| day.month() == self.month.month())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fails when the board of a date changes, which would give players different boards.
+    #[test]
+    fn layout_is_pinned_to_the_date() {
+        let challenge = DailyChallenge {
+            date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        };
+        let tile_map = challenge.layout().tile_map;
+        let mines: Vec<(u16, u16)> = tile_map
+            .mines()
+            .map(|coordinates| (coordinates.x, coordinates.y))
+            .collect();
+
+        assert_eq!((tile_map.width(), tile_map.height()), (23, 13));
+        assert_eq!(
+            mines,
+            vec![
+                (12, 0),
+                (18, 0),
+                (21, 0),
+                (5, 1),
+                (6, 1),
+                (15, 1),
+                (17, 2),
+                (18, 2),
+                (21, 2),
+                (12, 3),
+                (14, 3),
+                (20, 4),
+                (22, 4),
+                (0, 5),
+                (1, 5),
+                (4, 5),
+                (5, 5),
+                (9, 5),
+                (11, 5),
+                (17, 5),
+                (20, 5),
+                (2, 6),
+                (3, 6),
+                (18, 6),
+                (8, 7),
+                (9, 7),
+                (12, 7),
+                (16, 7),
+                (22, 7),
+                (0, 8),
+                (5, 8),
+                (4, 9),
+                (8, 9),
+                (19, 9),
+                (17, 10),
+                (5, 11),
+                (21, 11),
+                (10, 12),
+            ]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::components::Coordinates;
+use crate::resources::{BoardLayout, BoardMask, Solver, TileMap};
+
+/// Board being designed in the editor: mine positions plus the tiles revealed or flagged when the
+/// level starts.
+#[derive(Debug, Clone, Resource)]
+pub struct EditorBoard {
+    mask: BoardMask,
+    mines: HashSet<Coordinates>,
+    revealed: HashSet<Coordinates>,
+    flagged: HashSet<Coordinates>,
+}
+
+impl EditorBoard {
+    pub fn new(mask: BoardMask) -> Self {
+        Self {
+            mask,
+            mines: HashSet::new(),
+            revealed: HashSet::new(),
+            flagged: HashSet::new(),
+        }
+    }
+
+    pub fn mine_count(&self) -> usize {
+        self.mines.len()
+    }
+
+    pub fn revealed_count(&self) -> usize {
+        self.revealed.len()
+    }
+
+    /// Places or removes a mine. Mines cannot be revealed and safe tiles cannot be flagged, so
+    /// the tile state is reset.
+    pub fn toggle_mine(&mut self, coordinates: Coordinates) {
+        if !self.mask.contains(coordinates) {
+            return;
+        }
+
+        if !self.mines.remove(&coordinates) {
+            self.mines.insert(coordinates);
+        }
+        self.revealed.remove(&coordinates);
+        self.flagged.remove(&coordinates);
+    }
+
+    /// Toggles the initial state of a tile: flagged for mines, revealed for safe tiles.
+    pub fn toggle_initial_state(&mut self, coordinates: Coordinates) {
+        if !self.mask.contains(coordinates) {
+            return;
+        }
+
+        let set = if self.mines.contains(&coordinates) {
+            &mut self.flagged
+        } else {
+            &mut self.revealed
+        };
+
+        if !set.remove(&coordinates) {
+            set.insert(coordinates);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.mines.clear();
+        self.revealed.clear();
+        self.flagged.clear();
+    }
+
+    pub fn tile_map(&self) -> TileMap {
+        let mines: Vec<Coordinates> = self.mines.iter().copied().collect();
+        TileMap::from_mines(self.mask.clone(), &mines)
+    }
+
+    pub fn layout(&self) -> BoardLayout {
+        let mut revealed: Vec<Coordinates> = self.revealed.iter().copied().collect();
+        let mut flagged: Vec<Coordinates> = self.flagged.iter().copied().collect();
+        revealed.sort();
+        flagged.sort();
+
+        BoardLayout {
+            tile_map: self.tile_map(),
+            revealed,
+            flagged,
+        }
+    }
+
+    pub fn is_solvable(&self) -> bool {
+        let tile_map = self.tile_map();
+        Solver::new(
+            &tile_map,
+            self.revealed.iter().copied(),
+            self.flagged.iter().copied(),
+        )
+        .solve()
+    }
+
+    pub fn is_revealed(&self, coordinates: &Coordinates) -> bool {
+        self.revealed.contains(coordinates)
+    }
+
+    pub fn is_flagged(&self, coordinates: &Coordinates) -> bool {
+        self.flagged.contains(coordinates)
+    }
+}
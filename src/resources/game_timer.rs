@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// Time spent on the current board. It only advances while the board is being played and stops
+/// for good once the game is won or lost.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct GameTimer {
+    elapsed: Duration,
+    finished: bool,
+}
+
+impl GameTimer {
+    pub fn tick(&mut self, delta: Duration) {
+        if !self.finished {
+            self.elapsed += delta;
+        }
+    }
+
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
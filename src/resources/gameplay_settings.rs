@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+
+const GAMEPLAY_SETTINGS_FILE_NAME: &str = "gameplay.ron";
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ChordMode {
+    /// Uncovering a number chords it, as well as the button bound to `Action::Chord`.
+    #[default]
+    Uncover,
+    /// Only the button bound to `Action::Chord` chords.
+    ChordButton,
+    Disabled,
+}
+
+/// Optional game rules, persisted between sessions.
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct GameplaySettings {
+    /// Marking a flagged tile again puts a question mark on it instead of removing the flag.
+    pub question_marks: bool,
+    pub chord_mode: ChordMode,
+}
+
+impl GameplaySettings {
+    pub fn load() -> Self {
+        persistence::load(GAMEPLAY_SETTINGS_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        persistence::save(GAMEPLAY_SETTINGS_FILE_NAME, self);
+    }
+
+    /// Whether an uncovered number can be chorded, either with the chord button or by
+    /// uncovering it.
+    pub fn allows_chord(&self, chord_button: bool) -> bool {
+        match self.chord_mode {
+            ChordMode::Uncover => true,
+            ChordMode::ChordButton => chord_button,
+            ChordMode::Disabled => false,
+        }
+    }
+}
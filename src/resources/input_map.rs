@@ -0,0 +1,433 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+
+const INPUT_MAP_FILE_NAME: &str = "input.ron";
+
+/// Everything the player can do with a key or a mouse button.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Action {
+    Uncover,
+    Flag,
+    Chord,
+    CursorLeft,
+    CursorRight,
+    CursorUp,
+    CursorDown,
+    NextUnresolved,
+    Pause,
+    ClearBoard,
+    ExportBoard,
+    DescribeBoard,
+    Restart,
+    OpenPuzzles,
+    OpenEditor,
+    OpenDaily,
+    OpenControls,
+    Back,
+    PreviousMonth,
+    NextMonth,
+    StartDaily,
+    ToggleMine,
+    ToggleCover,
+    SaveLevel,
+    ClearLevel,
+    ClearBindings,
+    PanCamera,
+    ZoomIn,
+    ZoomOut,
+    ZoomToFit,
+    ToggleVsync,
+    ToggleFullscreen,
+    NextMonitor,
+    NextTheme,
+    ToggleAnimations,
+    ToggleMute,
+}
+
+/// Game states in which an action is handled. Bindings only conflict when their actions can
+/// be triggered at the same time.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ActionContext {
+    /// While playing a board.
+    Game,
+    /// On the menu screens and in the board editor.
+    Menu,
+    /// In every state.
+    Global,
+}
+
+impl ActionContext {
+    fn overlaps(&self, other: &ActionContext) -> bool {
+        self == other || *self == ActionContext::Global || *other == ActionContext::Global
+    }
+}
+
+impl Action {
+    pub const ALL: [Action; 36] = [
+        Action::Uncover,
+        Action::Flag,
+        Action::Chord,
+        Action::CursorLeft,
+        Action::CursorRight,
+        Action::CursorUp,
+        Action::CursorDown,
+        Action::NextUnresolved,
+        Action::Pause,
+        Action::ClearBoard,
+        Action::ExportBoard,
+        Action::DescribeBoard,
+        Action::Restart,
+        Action::OpenPuzzles,
+        Action::OpenEditor,
+        Action::OpenDaily,
+        Action::OpenControls,
+        Action::Back,
+        Action::PreviousMonth,
+        Action::NextMonth,
+        Action::StartDaily,
+        Action::ToggleMine,
+        Action::ToggleCover,
+        Action::SaveLevel,
+        Action::ClearLevel,
+        Action::ClearBindings,
+        Action::PanCamera,
+        Action::ZoomIn,
+        Action::ZoomOut,
+        Action::ZoomToFit,
+        Action::ToggleVsync,
+        Action::ToggleFullscreen,
+        Action::NextMonitor,
+        Action::NextTheme,
+        Action::ToggleAnimations,
+        Action::ToggleMute,
+    ];
+
+    pub fn context(&self) -> ActionContext {
+        match self {
+            Action::Uncover
+            | Action::Flag
+            | Action::Chord
+            | Action::CursorLeft
+            | Action::CursorRight
+            | Action::CursorUp
+            | Action::CursorDown
+            | Action::NextUnresolved
+            | Action::Pause
+            | Action::ClearBoard
+            | Action::ExportBoard
+            | Action::DescribeBoard => ActionContext::Game,
+            Action::Restart
+            | Action::OpenPuzzles
+            | Action::OpenEditor
+            | Action::OpenDaily
+            | Action::OpenControls
+            | Action::Back
+            | Action::PreviousMonth
+            | Action::NextMonth
+            | Action::StartDaily
+            | Action::ToggleMine
+            | Action::ToggleCover
+            | Action::SaveLevel
+            | Action::ClearLevel
+            | Action::ClearBindings => ActionContext::Menu,
+            Action::PanCamera
+            | Action::ZoomIn
+            | Action::ZoomOut
+            | Action::ZoomToFit
+            | Action::ToggleVsync
+            | Action::ToggleFullscreen
+            | Action::NextMonitor
+            | Action::NextTheme
+            | Action::ToggleAnimations
+            | Action::ToggleMute => ActionContext::Global,
+        }
+    }
+
+    /// Id of the translated name of the action, see `Localization`.
+    pub fn message_id(&self) -> &'static str {
+        match self {
+            Action::Uncover => "action-uncover",
+            Action::Flag => "action-flag",
+            Action::Chord => "action-chord",
+            Action::CursorLeft => "action-cursor-left",
+            Action::CursorRight => "action-cursor-right",
+            Action::CursorUp => "action-cursor-up",
+            Action::CursorDown => "action-cursor-down",
+            Action::NextUnresolved => "action-next-unresolved",
+            Action::Pause => "action-pause",
+            Action::ClearBoard => "action-clear-board",
+            Action::ExportBoard => "action-export-board",
+            Action::DescribeBoard => "action-describe-board",
+            Action::Restart => "action-restart",
+            Action::OpenPuzzles => "action-open-puzzles",
+            Action::OpenEditor => "action-open-editor",
+            Action::OpenDaily => "action-open-daily",
+            Action::OpenControls => "action-open-controls",
+            Action::Back => "action-back",
+            Action::PreviousMonth => "action-previous-month",
+            Action::NextMonth => "action-next-month",
+            Action::StartDaily => "action-start-daily",
+            Action::ToggleMine => "action-toggle-mine",
+            Action::ToggleCover => "action-toggle-cover",
+            Action::SaveLevel => "action-save-level",
+            Action::ClearLevel => "action-clear-level",
+            Action::ClearBindings => "action-clear-bindings",
+            Action::PanCamera => "action-pan-camera",
+            Action::ZoomIn => "action-zoom-in",
+            Action::ZoomOut => "action-zoom-out",
+            Action::ZoomToFit => "action-zoom-to-fit",
+            Action::ToggleVsync => "action-toggle-vsync",
+            Action::ToggleFullscreen => "action-toggle-fullscreen",
+            Action::NextMonitor => "action-next-monitor",
+            Action::NextTheme => "action-next-theme",
+            Action::ToggleAnimations => "action-toggle-animations",
+            Action::ToggleMute => "action-toggle-mute",
+        }
+    }
+
+    fn default_bindings(&self) -> Vec<InputBinding> {
+        use InputBinding::{Key, Mouse};
+
+        match self {
+            Action::Uncover => vec![Mouse(MouseButton::Left), Key(KeyCode::Space)],
+            Action::Flag => vec![Mouse(MouseButton::Right), Key(KeyCode::F)],
+            Action::Chord => vec![Key(KeyCode::Return)],
+            Action::CursorLeft => vec![Key(KeyCode::Left), Key(KeyCode::A), Key(KeyCode::H)],
+            Action::CursorRight => vec![Key(KeyCode::Right), Key(KeyCode::D), Key(KeyCode::L)],
+            Action::CursorUp => vec![Key(KeyCode::Up), Key(KeyCode::W), Key(KeyCode::K)],
+            Action::CursorDown => vec![Key(KeyCode::Down), Key(KeyCode::S), Key(KeyCode::J)],
+            Action::NextUnresolved => vec![Key(KeyCode::Tab)],
+            Action::Pause => vec![Key(KeyCode::Escape)],
+            Action::ClearBoard => vec![Key(KeyCode::C)],
+            Action::ExportBoard => vec![Key(KeyCode::E)],
+            Action::DescribeBoard => vec![Key(KeyCode::Q)],
+            Action::Restart => vec![Key(KeyCode::R)],
+            Action::OpenPuzzles => vec![Key(KeyCode::P)],
+            Action::OpenEditor => vec![Key(KeyCode::B)],
+            Action::OpenDaily => vec![Key(KeyCode::D)],
+            Action::OpenControls => vec![Key(KeyCode::I)],
+            Action::Back => vec![Key(KeyCode::Escape)],
+            Action::PreviousMonth => vec![Key(KeyCode::Left)],
+            Action::NextMonth => vec![Key(KeyCode::Right)],
+            Action::StartDaily => vec![Key(KeyCode::Return)],
+            Action::ToggleMine => vec![Mouse(MouseButton::Left)],
+            Action::ToggleCover => vec![Mouse(MouseButton::Right)],
+            Action::SaveLevel => vec![Key(KeyCode::S)],
+            Action::ClearLevel => vec![Key(KeyCode::X)],
+            Action::ClearBindings => vec![Key(KeyCode::Back)],
+            Action::PanCamera => vec![Mouse(MouseButton::Middle)],
+            Action::ZoomIn => vec![Key(KeyCode::Equals), Key(KeyCode::NumpadAdd)],
+            Action::ZoomOut => vec![Key(KeyCode::Minus), Key(KeyCode::NumpadSubtract)],
+            Action::ZoomToFit => vec![Key(KeyCode::Z)],
+            Action::ToggleVsync => vec![Key(KeyCode::V)],
+            Action::ToggleFullscreen => vec![Key(KeyCode::F11)],
+            Action::NextMonitor => vec![Key(KeyCode::F10)],
+            Action::NextTheme => vec![Key(KeyCode::T)],
+            Action::ToggleAnimations => vec![Key(KeyCode::N)],
+            Action::ToggleMute => vec![Key(KeyCode::M)],
+        }
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Action::Uncover => "Uncover",
+            Action::Flag => "Flag",
+            Action::Chord => "Chord",
+            Action::CursorLeft => "Cursor left",
+            Action::CursorRight => "Cursor right",
+            Action::CursorUp => "Cursor up",
+            Action::CursorDown => "Cursor down",
+            Action::NextUnresolved => "Next unresolved tile",
+            Action::Pause => "Pause",
+            Action::ClearBoard => "Clear the board",
+            Action::ExportBoard => "Export the board",
+            Action::DescribeBoard => "Describe the board",
+            Action::Restart => "New board",
+            Action::OpenPuzzles => "Puzzles",
+            Action::OpenEditor => "Board editor",
+            Action::OpenDaily => "Daily challenge",
+            Action::OpenControls => "Controls",
+            Action::Back => "Back",
+            Action::PreviousMonth => "Previous month",
+            Action::NextMonth => "Next month",
+            Action::StartDaily => "Play today's challenge",
+            Action::ToggleMine => "Editor: toggle mine",
+            Action::ToggleCover => "Editor: reveal / flag",
+            Action::SaveLevel => "Editor: save",
+            Action::ClearLevel => "Editor: clear",
+            Action::ClearBindings => "Remove bindings",
+            Action::PanCamera => "Pan (hold)",
+            Action::ZoomIn => "Zoom in",
+            Action::ZoomOut => "Zoom out",
+            Action::ZoomToFit => "Zoom to fit",
+            Action::ToggleVsync => "Toggle vsync",
+            Action::ToggleFullscreen => "Toggle fullscreen",
+            Action::NextMonitor => "Next monitor",
+            Action::NextTheme => "Next theme",
+            Action::ToggleAnimations => "Toggle animations",
+            Action::ToggleMute => "Toggle mute",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+impl Display for InputBinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputBinding::Key(key) => write!(f, "{:?}", key),
+            InputBinding::Mouse(MouseButton::Left) => write!(f, "LMB"),
+            InputBinding::Mouse(MouseButton::Right) => write!(f, "RMB"),
+            InputBinding::Mouse(MouseButton::Middle) => write!(f, "MMB"),
+            InputBinding::Mouse(MouseButton::Other(button)) => write!(f, "Mouse {}", button),
+        }
+    }
+}
+
+/// Keys and mouse buttons bound to every action. Persisted between sessions, actions missing
+/// from the saved file keep their default bindings.
+#[derive(Debug, Clone, Serialize, Deserialize, Resource)]
+pub struct InputMap {
+    bindings: BTreeMap<Action, Vec<InputBinding>>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self {
+            bindings: Action::ALL
+                .iter()
+                .map(|action| (*action, action.default_bindings()))
+                .collect(),
+        }
+    }
+}
+
+impl InputMap {
+    pub fn load() -> Self {
+        let mut input_map = Self::default();
+        if let Some(saved) = persistence::load::<Self>(INPUT_MAP_FILE_NAME) {
+            input_map.bindings.extend(saved.bindings);
+        }
+
+        for (binding, first, second) in input_map.conflicts() {
+            warn!(
+                "{} is bound to both '{}' and '{}', only one of them will work reliably",
+                binding, first, second
+            );
+        }
+        input_map
+    }
+
+    pub fn save(&self) {
+        persistence::save(INPUT_MAP_FILE_NAME, self);
+    }
+
+    pub fn bindings(&self, action: Action) -> &[InputBinding] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn keys(&self, action: Action) -> impl Iterator<Item = KeyCode> + '_ {
+        self.bindings(action)
+            .iter()
+            .filter_map(|binding| match binding {
+                InputBinding::Key(key) => Some(*key),
+                InputBinding::Mouse(_) => None,
+            })
+    }
+
+    pub fn buttons(&self, action: Action) -> impl Iterator<Item = MouseButton> + '_ {
+        self.bindings(action)
+            .iter()
+            .filter_map(|binding| match binding {
+                InputBinding::Key(_) => None,
+                InputBinding::Mouse(button) => Some(*button),
+            })
+    }
+
+    pub fn pressed(
+        &self,
+        action: Action,
+        keys: &Input<KeyCode>,
+        buttons: &Input<MouseButton>,
+    ) -> bool {
+        keys.any_pressed(self.keys(action)) || buttons.any_pressed(self.buttons(action))
+    }
+
+    pub fn just_pressed(
+        &self,
+        action: Action,
+        keys: &Input<KeyCode>,
+        buttons: &Input<MouseButton>,
+    ) -> bool {
+        keys.any_just_pressed(self.keys(action)) || buttons.any_just_pressed(self.buttons(action))
+    }
+
+    /// Bindings of an action joined for log messages and hints, e.g. `Left/A/H`.
+    pub fn label(&self, action: Action) -> String {
+        let bindings = self.bindings(action);
+        if bindings.is_empty() {
+            return String::from("unbound");
+        }
+        bindings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Adds a binding to an action, it is a no-op when the action already has it.
+    pub fn bind(&mut self, action: Action, binding: InputBinding) {
+        let bindings = self.bindings.entry(action).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    pub fn unbind_all(&mut self, action: Action) {
+        self.bindings.insert(action, Vec::new());
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Bindings shared by two actions that can be triggered in the same state.
+    pub fn conflicts(&self) -> Vec<(InputBinding, Action, Action)> {
+        let mut conflicts = Vec::new();
+        for (index, first) in Action::ALL.iter().enumerate() {
+            for second in Action::ALL.iter().skip(index + 1) {
+                if !first.context().overlaps(&second.context()) {
+                    continue;
+                }
+                for binding in self.bindings(*first) {
+                    if self.bindings(*second).contains(binding) {
+                        conflicts.push((*binding, *first, *second));
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    pub fn has_conflict(&self, action: Action) -> bool {
+        self.conflicts()
+            .iter()
+            .any(|(_, first, second)| *first == action || *second == action)
+    }
+}
+
+/// Action waiting for a key or mouse button on the controls screen.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Resource)]
+pub struct RebindCapture(pub Option<Action>);
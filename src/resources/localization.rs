@@ -0,0 +1,178 @@
+use std::path::Path;
+
+use bevy::prelude::*;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use serde::{Deserialize, Serialize};
+use unic_langid::LanguageIdentifier;
+
+use crate::persistence;
+
+const LOCALES_DIRECTORY: &str = "locales";
+const LOCALE_FILE_NAME: &str = "main.ftl";
+const LANGUAGE_SETTINGS_FILE_NAME: &str = "language.ron";
+/// Every message exists in this language, it is used for messages missing from the others.
+const FALLBACK_LANGUAGE: &str = "en-US";
+/// Message holding the name of a language, written in that language.
+const LANGUAGE_NAME_MESSAGE: &str = "language-name";
+
+/// Language picked by the player. Without one, the language of the system is used.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct LanguageSettings {
+    language: Option<String>,
+}
+
+type Bundle = FluentBundle<FluentResource>;
+
+/// UI texts of the selected language, read from the Fluent files in `assets/locales`. Messages
+/// missing from the selected language are taken from English.
+#[derive(Resource)]
+pub struct Localization {
+    languages: Vec<LanguageIdentifier>,
+    selected: usize,
+    bundle: Bundle,
+    fallback: Bundle,
+}
+
+impl Localization {
+    /// Finds the available languages and selects the saved one, or the system language.
+    pub fn load() -> Self {
+        let directory = persistence::assets_directory().join(LOCALES_DIRECTORY);
+        let fallback_language: LanguageIdentifier = FALLBACK_LANGUAGE.parse().unwrap();
+
+        let mut languages: Vec<LanguageIdentifier> = match std::fs::read_dir(&directory) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().join(LOCALE_FILE_NAME).is_file())
+                .filter_map(|entry| entry.file_name().to_string_lossy().parse().ok())
+                .collect(),
+            Err(e) => {
+                warn!("Failed to read locales from {}: {}", directory.display(), e);
+                Vec::new()
+            }
+        };
+        // English first, so it is the default when nothing else matches
+        languages.retain(|language| *language != fallback_language);
+        languages.sort_by_key(|language| language.to_string());
+        languages.insert(0, fallback_language.clone());
+
+        let settings: LanguageSettings =
+            persistence::load(LANGUAGE_SETTINGS_FILE_NAME).unwrap_or_default();
+        let requested = settings.language.or_else(sys_locale::get_locale);
+        let selected = requested
+            .and_then(|requested| Self::negotiate(&languages, &requested))
+            .unwrap_or_default();
+
+        let fallback = Self::load_bundle(&directory, &fallback_language);
+        let bundle = Self::load_bundle(&directory, &languages[selected]);
+        info!("Using the {} language", languages[selected]);
+
+        Self {
+            languages,
+            selected,
+            bundle,
+            fallback,
+        }
+    }
+
+    pub fn save(&self) {
+        persistence::save(
+            LANGUAGE_SETTINGS_FILE_NAME,
+            &LanguageSettings {
+                language: Some(self.language().to_string()),
+            },
+        );
+    }
+
+    pub fn language(&self) -> &LanguageIdentifier {
+        &self.languages[self.selected]
+    }
+
+    /// Name of the selected language, in that language.
+    pub fn language_name(&self) -> String {
+        self.text(LANGUAGE_NAME_MESSAGE)
+    }
+
+    pub fn select_next(&mut self) {
+        self.select((self.selected + 1) % self.languages.len());
+    }
+
+    pub fn select_previous(&mut self) {
+        self.select((self.selected + self.languages.len() - 1) % self.languages.len());
+    }
+
+    /// Text of a message without arguments.
+    pub fn text(&self, id: &str) -> String {
+        self.format(id, None)
+    }
+
+    /// Text of a message with arguments. Numbers select the plural form of the language.
+    pub fn text_with(&self, id: &str, args: &[(&str, FluentValue)]) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+        self.format(id, Some(&fluent_args))
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        let directory = persistence::assets_directory().join(LOCALES_DIRECTORY);
+        self.bundle = Self::load_bundle(&directory, &self.languages[index]);
+    }
+
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        for bundle in [&self.bundle, &self.fallback] {
+            let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+                continue;
+            };
+            let mut errors = Vec::new();
+            let text = bundle.format_pattern(pattern, args, &mut errors);
+            if !errors.is_empty() {
+                warn!("Failed to format message '{}': {:?}", id, errors);
+            }
+            return text.into_owned();
+        }
+
+        warn!("Missing message '{}'", id);
+        id.to_string()
+    }
+
+    /// Index of the requested language, or of a language sharing its primary language, like
+    /// `de` for `de-AT`.
+    fn negotiate(languages: &[LanguageIdentifier], requested: &str) -> Option<usize> {
+        let requested: LanguageIdentifier = requested.parse().ok()?;
+        languages
+            .iter()
+            .position(|language| *language == requested)
+            .or_else(|| {
+                languages
+                    .iter()
+                    .position(|language| language.language == requested.language)
+            })
+    }
+
+    fn load_bundle(directory: &Path, language: &LanguageIdentifier) -> Bundle {
+        let mut bundle = Bundle::new_concurrent(vec![language.clone()]);
+        // Unicode isolation marks would have to be covered by the fonts
+        bundle.set_use_isolating(false);
+
+        let path = directory.join(language.to_string()).join(LOCALE_FILE_NAME);
+        let source = match std::fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(e) => {
+                error!("Failed to read {}: {}", path.display(), e);
+                return bundle;
+            }
+        };
+        let resource = FluentResource::try_new(source).unwrap_or_else(|(resource, errors)| {
+            warn!("Failed to parse parts of {}: {:?}", path.display(), errors);
+            resource
+        });
+        if let Err(errors) = bundle.add_resource(resource) {
+            warn!("Duplicate messages in {}: {:?}", path.display(), errors);
+        }
+        bundle
+    }
+}
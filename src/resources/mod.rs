@@ -1,14 +1,81 @@
+pub use accessibility_settings::AccessibilitySettings;
+pub use accessibility_settings::NumberDecoration;
+pub use accessibility_settings::NumberPalette;
+pub use animation_settings::AnimationSettings;
+pub use animation_settings::UncoverAnimation;
+pub use audio_settings::AudioSettings;
 pub use board::Board;
+pub use board::TileMark;
 pub use board_assets::BoardAssets;
+pub use board_assets::EffectAssets;
+pub use board_assets::SoundAssets;
 pub use board_assets::SpriteMaterial;
+pub use board_format::BoardFormat;
+pub use board_format::BoardFormatError;
+pub use board_layout::BoardLayout;
+pub use board_mask::BoardMask;
+pub use board_mask::BoardMaskError;
 pub use board_options::BoardOptions;
 pub use board_options::BoardPosition;
 pub use board_options::TileSize;
+pub use daily::DailyCalendar;
+pub use daily::DailyChallenge;
+pub use daily::DailyHistory;
+pub use daily::DailyResult;
+pub use editor_board::EditorBoard;
+pub use game_timer::GameTimer;
+pub use gameplay_settings::ChordMode;
+pub use gameplay_settings::GameplaySettings;
+pub use input_map::Action;
+pub use input_map::InputBinding;
+pub use input_map::InputMap;
+pub use input_map::RebindCapture;
+pub use localization::Localization;
+pub use puzzle::ActivePuzzle;
+pub use puzzle::PuzzleLevel;
+pub use puzzle::PuzzleLevelFile;
+pub use puzzle::PuzzlePack;
+pub use puzzle::PuzzleProgress;
+pub use solver::Solver;
+pub use statistics::Statistics;
+pub use synth_sound::Note;
+pub use synth_sound::SynthSound;
+pub use synth_sound::Waveform;
+pub use theme::ThemeDefinition;
+pub use theme::ThemeError;
+pub use theme::Themes;
+pub use theme::THEME_FILE_EXTENSION;
 pub use tile::Tile;
+pub use tile_cursor::TileCursor;
 pub use tile_map::TileMap;
+pub use tile_pointer::TilePointer;
+pub use tile_pointer::TilePress;
+pub use touch_mode::TouchMode;
+pub use window_settings::WindowSettings;
 
+mod accessibility_settings;
+mod animation_settings;
+mod audio_settings;
 mod board;
 mod board_assets;
+mod board_format;
+mod board_layout;
+mod board_mask;
 mod board_options;
+mod daily;
+mod editor_board;
+mod game_timer;
+mod gameplay_settings;
+mod input_map;
+mod localization;
+mod puzzle;
+mod solver;
+mod statistics;
+mod synth_sound;
+mod theme;
 mod tile;
+mod tile_cursor;
 mod tile_map;
+mod tile_pointer;
+mod touch_mode;
+mod window_settings;
@@ -0,0 +1,279 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::Coordinates;
+use crate::persistence;
+use crate::resources::{BoardFormat, BoardFormatError, BoardLayout, Solver};
+
+const PROGRESS_FILE_NAME: &str = "puzzle_progress.ron";
+const PUZZLES_DIRECTORY: &str = "puzzles";
+
+#[derive(Debug)]
+pub enum PuzzleError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+    Serialize(ron::Error),
+    Board(BoardFormatError),
+    InvalidCharacter { character: char, row: usize },
+    NotSolvable,
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read puzzle file: {}", e),
+            Self::Parse(e) => write!(f, "failed to parse puzzle file: {}", e),
+            Self::Serialize(e) => write!(f, "failed to serialize puzzle: {}", e),
+            Self::Board(e) => write!(f, "invalid puzzle board: {}", e),
+            Self::InvalidCharacter { character, row } => {
+                write!(f, "invalid character '{}' in row {}", character, row)
+            }
+            Self::NotSolvable => write!(f, "puzzle cannot be solved without guessing"),
+        }
+    }
+}
+
+impl From<std::io::Error> for PuzzleError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for PuzzleError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<BoardFormatError> for PuzzleError {
+    fn from(e: BoardFormatError) -> Self {
+        Self::Board(e)
+    }
+}
+
+/// On-disk representation of a puzzle. Rows are listed top to bottom using:
+/// * `*`: covered mine
+/// * `F`: flagged mine
+/// * `.`: covered safe tile
+/// * `o`: revealed safe tile
+/// * ` `: hole
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PuzzleLevelFile {
+    pub name: String,
+    pub rows: Vec<String>,
+}
+
+impl PuzzleLevelFile {
+    pub fn from_layout(name: String, layout: &BoardLayout) -> Self {
+        let tile_map = &layout.tile_map;
+        let rows = (0..tile_map.height())
+            .rev()
+            .map(|y| {
+                (0..tile_map.width())
+                    .map(|x| {
+                        let coordinates = Coordinates { x, y };
+                        if !tile_map.has_tile_at(coordinates) {
+                            ' '
+                        } else if layout.flagged.contains(&coordinates) {
+                            'F'
+                        } else if tile_map.is_mine_at(coordinates) {
+                            '*'
+                        } else if layout.revealed.contains(&coordinates) {
+                            'o'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self { name, rows }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), PuzzleError> {
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(PuzzleError::Serialize)?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PuzzleLevel {
+    pub id: String,
+    pub name: String,
+    pub layout: BoardLayout,
+}
+
+impl PuzzleLevel {
+    /// Parses a puzzle and checks that it can be solved without guessing.
+    pub fn parse(id: String, file: PuzzleLevelFile) -> Result<Self, PuzzleError> {
+        let mut board = String::new();
+        let mut revealed = Vec::new();
+        let mut flagged = Vec::new();
+        // Rows of holes are kept by the text format, so the rows match the board rows
+        let height = file.rows.len() as u16;
+
+        for (row, line) in file.rows.iter().enumerate() {
+            let y = height - 1 - row as u16;
+            for (x, character) in line.chars().enumerate() {
+                let coordinates = Coordinates { x: x as u16, y };
+                board.push(match character {
+                    '*' | '.' | ' ' => character,
+                    'F' => {
+                        flagged.push(coordinates);
+                        '*'
+                    }
+                    'o' => {
+                        revealed.push(coordinates);
+                        '.'
+                    }
+                    _ => {
+                        return Err(PuzzleError::InvalidCharacter {
+                            character,
+                            row: row + 1,
+                        })
+                    }
+                });
+            }
+            board.push('\n');
+        }
+
+        let tile_map = BoardFormat::Text.read(board.as_bytes())?;
+        let solver = Solver::new(&tile_map, revealed.iter().copied(), flagged.iter().copied());
+        if !solver.solve() {
+            return Err(PuzzleError::NotSolvable);
+        }
+
+        Ok(Self {
+            id,
+            name: file.name,
+            layout: BoardLayout {
+                tile_map,
+                revealed,
+                flagged,
+            },
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, PuzzleError> {
+        let id = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file: PuzzleLevelFile = ron::from_str(&std::fs::read_to_string(path)?)?;
+        Self::parse(id, file)
+    }
+}
+
+/// Puzzles available to the player, the shipped ones first, each ordered by file name.
+#[derive(Debug, Default, Resource)]
+pub struct PuzzlePack {
+    pub levels: Vec<PuzzleLevel>,
+}
+
+impl PuzzlePack {
+    /// Directory of the puzzles shipped with the game.
+    pub fn directory() -> PathBuf {
+        persistence::assets_directory().join(PUZZLES_DIRECTORY)
+    }
+
+    /// Directory the editor saves new levels to, in the platform data directory since the
+    /// assets may not be writable.
+    pub fn user_directory() -> PathBuf {
+        persistence::data_directory().join(PUZZLES_DIRECTORY)
+    }
+
+    /// Loads the shipped puzzles followed by the ones saved from the editor.
+    pub fn load() -> Self {
+        let mut levels = Self::load_directory(&Self::directory());
+        levels.extend(Self::load_directory(&Self::user_directory()));
+        Self { levels }
+    }
+
+    /// Loads every `.ron` puzzle from a directory, ordered by file name. Invalid puzzles are
+    /// reported and skipped.
+    fn load_directory(directory: &Path) -> Vec<PuzzleLevel> {
+        let mut paths: Vec<_> = match std::fs::read_dir(directory) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+                .collect(),
+            // Nothing was saved from the editor yet
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => {
+                warn!("Failed to read puzzles from {}: {}", directory.display(), e);
+                Vec::new()
+            }
+        };
+        paths.sort();
+
+        paths
+            .iter()
+            .filter_map(|path| match PuzzleLevel::load(path) {
+                Ok(level) => Some(level),
+                Err(e) => {
+                    warn!("Skipping puzzle {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Identifiers of the puzzles solved by the player, persisted between sessions.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Resource)]
+pub struct PuzzleProgress {
+    completed: Vec<String>,
+}
+
+impl PuzzleProgress {
+    pub fn load() -> Self {
+        persistence::load(PROGRESS_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn is_completed(&self, id: &str) -> bool {
+        self.completed.iter().any(|completed| completed == id)
+    }
+
+    pub fn complete(&mut self, id: &str) {
+        if !self.is_completed(id) {
+            self.completed.push(id.to_string());
+            persistence::save(PROGRESS_FILE_NAME, self);
+        }
+    }
+}
+
+/// Index of the puzzle currently being played in the `PuzzlePack`.
+#[derive(Debug, Copy, Clone, Resource)]
+pub struct ActivePuzzle(pub usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_keeps_rows_of_holes() {
+        let file = PuzzleLevelFile {
+            name: "Gap".into(),
+            rows: vec!["o.F".into(), "".into(), "ooo".into()],
+        };
+
+        let level = PuzzleLevel::parse("gap".into(), file).unwrap();
+        let tile_map = &level.layout.tile_map;
+
+        assert_eq!((tile_map.width(), tile_map.height()), (3, 3));
+        assert!(!tile_map.has_tile_at(Coordinates { x: 1, y: 1 }));
+        assert_eq!(level.layout.flagged, vec![Coordinates { x: 2, y: 2 }]);
+        assert!(tile_map.is_mine_at(Coordinates { x: 2, y: 2 }));
+        for coordinates in &level.layout.revealed {
+            assert!(tile_map.has_tile_at(*coordinates));
+            assert!(!tile_map.is_mine_at(*coordinates));
+        }
+        assert_eq!(level.layout.revealed.len(), 4);
+    }
+}
@@ -0,0 +1,206 @@
+use bevy::utils::HashSet;
+
+use crate::components::Coordinates;
+use crate::resources::TileMap;
+
+/// A revealed number together with the covered tiles around it and the amount of mines still
+/// hidden among them.
+struct Constraint {
+    unknown: HashSet<Coordinates>,
+    mines: usize,
+}
+
+/// Deterministic solver used to check that a position can be cleared without guessing.
+///
+/// It only applies logical deductions (single tile constraints, subset constraints between pairs
+/// of tiles and the global mine count), so a board it solves has exactly one solution. The mine
+/// count is shown to the player, as a counter while playing a puzzle and in the editor status.
+pub struct Solver<'a> {
+    tile_map: &'a TileMap,
+    revealed: HashSet<Coordinates>,
+    flagged: HashSet<Coordinates>,
+}
+
+impl<'a> Solver<'a> {
+    pub fn new(
+        tile_map: &'a TileMap,
+        revealed: impl IntoIterator<Item = Coordinates>,
+        flagged: impl IntoIterator<Item = Coordinates>,
+    ) -> Self {
+        let mut solver = Self {
+            tile_map,
+            revealed: HashSet::new(),
+            flagged: flagged.into_iter().collect(),
+        };
+
+        for coordinates in revealed {
+            solver.reveal(coordinates);
+        }
+
+        solver
+    }
+
+    /// Returns `true` if every safe tile can be uncovered by deduction alone.
+    pub fn solve(mut self) -> bool {
+        while self.apply_constraints() || self.apply_mine_count() {}
+        self.is_solved()
+    }
+
+    fn is_solved(&self) -> bool {
+        self.revealed.len() + self.tile_map.mine_count() as usize == self.tile_map.tile_count()
+    }
+
+    fn neighbors(&self, coordinates: Coordinates) -> impl Iterator<Item = Coordinates> + '_ {
+        self.tile_map
+            .safe_square_at(coordinates)
+            .filter(|coords| self.tile_map.has_tile_at(*coords))
+    }
+
+    fn is_unknown(&self, coordinates: &Coordinates) -> bool {
+        !self.revealed.contains(coordinates) && !self.flagged.contains(coordinates)
+    }
+
+    /// Reveals a safe tile, cascading through empty tiles the same way the game does.
+    fn reveal(&mut self, coordinates: Coordinates) {
+        let mut pending = vec![coordinates];
+        while let Some(coords) = pending.pop() {
+            if self.tile_map.is_mine_at(coords) || !self.revealed.insert(coords) {
+                continue;
+            }
+
+            if self.tile_map.mine_count_at(coords) == 0 {
+                pending.extend(self.neighbors(coords).filter(|c| self.is_unknown(c)));
+            }
+        }
+    }
+
+    fn constraints(&self) -> Vec<Constraint> {
+        self.revealed
+            .iter()
+            .filter_map(|coords| {
+                let unknown: HashSet<Coordinates> = self
+                    .neighbors(*coords)
+                    .filter(|c| self.is_unknown(c))
+                    .collect();
+                if unknown.is_empty() {
+                    return None;
+                }
+
+                let flagged = self
+                    .neighbors(*coords)
+                    .filter(|c| self.flagged.contains(c))
+                    .count();
+                let mines = (self.tile_map.mine_count_at(*coords) as usize).saturating_sub(flagged);
+                Some(Constraint { unknown, mines })
+            })
+            .collect()
+    }
+
+    /// Applies the constraints of every revealed number, alone and in pairs.
+    /// Returns `true` if any tile was resolved.
+    fn apply_constraints(&mut self) -> bool {
+        let constraints = self.constraints();
+        let mut safe = HashSet::new();
+        let mut mines = HashSet::new();
+
+        for constraint in constraints.iter() {
+            if constraint.mines == 0 {
+                safe.extend(constraint.unknown.iter().copied());
+            } else if constraint.mines == constraint.unknown.len() {
+                mines.extend(constraint.unknown.iter().copied());
+            }
+        }
+
+        if safe.is_empty() && mines.is_empty() {
+            for a in constraints.iter() {
+                for b in constraints.iter() {
+                    if a.unknown.len() >= b.unknown.len() || !a.unknown.is_subset(&b.unknown) {
+                        continue;
+                    }
+
+                    let difference = b.unknown.difference(&a.unknown).copied();
+                    let remaining_mines = b.mines.saturating_sub(a.mines);
+                    if remaining_mines == 0 {
+                        safe.extend(difference);
+                    } else if remaining_mines == b.unknown.len() - a.unknown.len() {
+                        mines.extend(difference);
+                    }
+                }
+            }
+        }
+
+        self.resolve(safe, mines)
+    }
+
+    /// Resolves the remaining tiles when the number of mines left alone determines them.
+    fn apply_mine_count(&mut self) -> bool {
+        let unknown: HashSet<Coordinates> = (0..self.tile_map.height())
+            .flat_map(|y| (0..self.tile_map.width()).map(move |x| Coordinates { x, y }))
+            .filter(|c| self.tile_map.has_tile_at(*c) && self.is_unknown(c))
+            .collect();
+        let remaining_mines =
+            (self.tile_map.mine_count() as usize).saturating_sub(self.flagged.len());
+
+        if remaining_mines == 0 {
+            self.resolve(unknown, HashSet::new())
+        } else if remaining_mines == unknown.len() {
+            self.resolve(HashSet::new(), unknown)
+        } else {
+            false
+        }
+    }
+
+    /// Returns `true` if the known state changed. Comparing sizes instead of checking the inputs
+    /// guarantees termination even if inconsistent flags make the solver deduce nonsense.
+    fn resolve(&mut self, safe: HashSet<Coordinates>, mines: HashSet<Coordinates>) -> bool {
+        let known_before = self.revealed.len() + self.flagged.len();
+        self.flagged.extend(mines);
+        for coordinates in safe {
+            self.reveal(coordinates);
+        }
+        self.revealed.len() + self.flagged.len() != known_before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::BoardMask;
+
+    fn solve(width: u16, height: u16, mines: &[(u16, u16)], revealed: &[(u16, u16)]) -> bool {
+        let coordinates = |tiles: &[(u16, u16)]| -> Vec<Coordinates> {
+            tiles
+                .iter()
+                .map(|(x, y)| Coordinates { x: *x, y: *y })
+                .collect()
+        };
+        let tile_map =
+            TileMap::from_mines(BoardMask::rectangle(width, height), &coordinates(mines));
+        Solver::new(&tile_map, coordinates(revealed), Vec::new()).solve()
+    }
+
+    #[test]
+    fn solves_a_board_with_one_solution() {
+        // The cascade stops next to the mine, whose number leaves a single covered tile
+        assert!(solve(4, 1, &[(3, 0)], &[(0, 0)]));
+    }
+
+    #[test]
+    fn rejects_a_board_needing_a_guess() {
+        // One mine among the three covered neighbours of the revealed corner
+        assert!(!solve(2, 2, &[(1, 1)], &[(0, 0)]));
+    }
+
+    #[test]
+    fn solves_a_board_needing_the_subset_rule() {
+        // 1-2-1 below three covered tiles: no number decides on its own, but the 1 covers a
+        // subset of the 2, so the third tile under the 2 is a mine
+        assert!(solve(3, 2, &[(0, 1), (2, 1)], &[(0, 0), (1, 0), (2, 0)]));
+    }
+
+    #[test]
+    fn solves_a_board_needing_the_mine_count() {
+        // The last two tiles touch no number, they are safe because the only mine is found
+        assert!(solve(4, 1, &[(1, 0)], &[(0, 0)]));
+    }
+}
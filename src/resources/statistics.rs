@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+use crate::resources::TileMap;
+
+const STATISTICS_FILE_NAME: &str = "statistics.ron";
+
+/// Results of every finished board, persisted between sessions. Boards left before being won or
+/// lost are not counted.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Resource)]
+pub struct Statistics {
+    pub won: u32,
+    pub lost: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    /// Best time in seconds for every board size, see `Statistics::board_label`.
+    best_times: BTreeMap<String, f32>,
+}
+
+impl Statistics {
+    pub fn load() -> Self {
+        persistence::load(STATISTICS_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn played(&self) -> u32 {
+        self.won + self.lost
+    }
+
+    /// Share of won games, between 0 and 1.
+    pub fn win_rate(&self) -> f32 {
+        match self.played() {
+            0 => 0f32,
+            played => self.won as f32 / played as f32,
+        }
+    }
+
+    /// Boards are compared by their size and mine count, e.g. `20x20, 60 mines`.
+    pub fn board_label(tile_map: &TileMap) -> String {
+        format!(
+            "{}x{}, {} mines",
+            tile_map.width(),
+            tile_map.height(),
+            tile_map.mine_count()
+        )
+    }
+
+    /// Width, height and mine count of a board label, see `Statistics::board_label`.
+    pub fn board_size(board_label: &str) -> Option<(u16, u16, u16)> {
+        let (size, mines) = board_label.split_once(", ")?;
+        let (width, height) = size.split_once('x')?;
+        let mines = mines.strip_suffix(" mines")?;
+        Some((
+            width.parse().ok()?,
+            height.parse().ok()?,
+            mines.parse().ok()?,
+        ))
+    }
+
+    pub fn best_times(&self) -> impl Iterator<Item = (&String, f32)> {
+        self.best_times
+            .iter()
+            .map(|(label, seconds)| (label, *seconds))
+    }
+
+    /// Records a won board and returns whether it is a new best time for its size.
+    pub fn record_win(&mut self, board_label: String, seconds: f32) -> bool {
+        self.won += 1;
+        self.current_streak += 1;
+        self.best_streak = self.best_streak.max(self.current_streak);
+
+        let best = self.best_times.entry(board_label).or_insert(f32::MAX);
+        let new_best = seconds < *best;
+        if new_best {
+            *best = seconds;
+        }
+
+        self.save();
+        new_best
+    }
+
+    pub fn record_loss(&mut self) {
+        self.lost += 1;
+        self.current_streak = 0;
+        self.save();
+    }
+
+    fn save(&self) {
+        persistence::save(STATISTICS_FILE_NAME, self);
+    }
+}
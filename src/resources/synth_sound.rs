@@ -0,0 +1,166 @@
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use bevy::audio::{Decodable, Source};
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+
+const SAMPLE_RATE: u32 = 44_100;
+/// Seconds for a note to reach full volume, avoiding clicks.
+const ATTACK: f32 = 0.005;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Waveform {
+    Sine,
+    Square,
+    Triangle,
+    Noise,
+}
+
+/// A tone sweeping from one frequency to another, fading out over its duration.
+#[derive(Debug, Copy, Clone)]
+pub struct Note {
+    pub start_frequency: f32,
+    pub end_frequency: f32,
+    pub duration: f32,
+}
+
+impl Note {
+    pub fn sweep(start_frequency: f32, end_frequency: f32, duration: f32) -> Self {
+        Self {
+            start_frequency,
+            end_frequency,
+            duration,
+        }
+    }
+
+    pub fn tone(frequency: f32, duration: f32) -> Self {
+        Self::sweep(frequency, frequency, duration)
+    }
+}
+
+/// A sound generated on the fly from a sequence of notes, used for the built-in sound effects so
+/// the game does not need to ship audio files.
+#[derive(Debug, Clone, Asset, TypePath)]
+pub struct SynthSound {
+    pub waveform: Waveform,
+    pub notes: Vec<Note>,
+    pub volume: f32,
+}
+
+impl SynthSound {
+    pub fn new(waveform: Waveform, notes: Vec<Note>, volume: f32) -> Self {
+        Self {
+            waveform,
+            notes,
+            volume,
+        }
+    }
+
+    fn render(&self) -> Vec<f32> {
+        let mut samples = Vec::new();
+        let mut phase = 0f32;
+        let mut noise = NoiseGenerator::default();
+        let mut filtered = 0f32;
+
+        for note in self.notes.iter() {
+            let sample_count = (note.duration * SAMPLE_RATE as f32) as usize;
+            for i in 0..sample_count {
+                let t = i as f32 / sample_count as f32;
+                let frequency =
+                    note.start_frequency + (note.end_frequency - note.start_frequency) * t;
+                phase = (phase + frequency / SAMPLE_RATE as f32).fract();
+
+                let value = match self.waveform {
+                    Waveform::Sine => (phase * TAU).sin(),
+                    Waveform::Square => {
+                        if phase < 0.5 {
+                            1f32
+                        } else {
+                            -1f32
+                        }
+                    }
+                    Waveform::Triangle => 4f32 * (phase - 0.5).abs() - 1f32,
+                    Waveform::Noise => {
+                        // Low-pass filtered noise, the frequency controls how bright it sounds
+                        let alpha = (frequency / SAMPLE_RATE as f32 * TAU).min(1f32);
+                        filtered += alpha * (noise.next() - filtered);
+                        filtered * 2f32
+                    }
+                };
+
+                let elapsed = i as f32 / SAMPLE_RATE as f32;
+                let attack = (elapsed / ATTACK).min(1f32);
+                let decay = (1f32 - t).powi(2);
+                samples.push(value * attack * decay * self.volume);
+            }
+        }
+
+        samples
+    }
+}
+
+impl Decodable for SynthSound {
+    type DecoderItem = f32;
+    type Decoder = SynthDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        SynthDecoder {
+            samples: self.render(),
+            position: 0,
+        }
+    }
+}
+
+pub struct SynthDecoder {
+    samples: Vec<f32>,
+    position: usize,
+}
+
+impl Iterator for SynthDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.samples.get(self.position).copied();
+        self.position += 1;
+        sample
+    }
+}
+
+impl Source for SynthDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        Some(self.samples.len().saturating_sub(self.position))
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f32(
+            self.samples.len() as f32 / SAMPLE_RATE as f32,
+        ))
+    }
+}
+
+/// Xorshift noise, good enough for sound and deterministic between plays.
+struct NoiseGenerator(u32);
+
+impl Default for NoiseGenerator {
+    fn default() -> Self {
+        Self(0x2545_f491)
+    }
+}
+
+impl NoiseGenerator {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0 as f32 / u32::MAX as f32 * 2f32 - 1f32
+    }
+}
@@ -0,0 +1,460 @@
+use std::fmt::{Display, Formatter};
+
+use bevy::prelude::*;
+use bevy::window::WindowTheme;
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+use crate::resources::{BoardAssets, EffectAssets, NumberPalette, SoundAssets, SpriteMaterial};
+
+const THEMES_DIRECTORY: &str = "themes";
+pub const THEME_FILE_EXTENSION: &str = "theme.ron";
+const MINE_COUNTER_FONT: &str = "fonts/symtext/Symtext.ttf";
+const NUMBER_GLYPH_FONT: &str = "fonts/dejavu/DejaVuSans.ttf";
+const NUMBER_GLYPHS: [&str; 8] = ["●", "▲", "■", "◆", "★", "✚", "♥", "♣"];
+
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+    Color(String),
+}
+
+impl Display for ThemeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read theme file: {}", e),
+            Self::Parse(e) => write!(f, "failed to parse theme file: {}", e),
+            Self::Color(value) => write!(f, "invalid color '{}'", value),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+impl From<std::io::Error> for ThemeError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for ThemeError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// Serialized form of a `SpriteMaterial`. Colors are hex strings (`RRGGBB` or `RRGGBBAA`,
+/// optionally prefixed with `#`), textures are paths relative to the `assets` directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaterialDefinition {
+    pub color: String,
+    #[serde(default)]
+    pub texture: Option<String>,
+}
+
+impl MaterialDefinition {
+    fn color(color: &str) -> Self {
+        Self {
+            color: color.to_string(),
+            texture: None,
+        }
+    }
+
+    fn textured(color: &str, texture: &str) -> Self {
+        Self {
+            color: color.to_string(),
+            texture: Some(texture.to_string()),
+        }
+    }
+
+    fn sprite_material(&self, asset_server: &AssetServer) -> Result<SpriteMaterial, ThemeError> {
+        Ok(SpriteMaterial {
+            color: parse_color(&self.color)?,
+            texture: match &self.texture {
+                Some(path) => asset_server.load(path),
+                None => Handle::default(),
+            },
+        })
+    }
+}
+
+/// Serialized form of the `EffectAssets`. Themes without an `effects` section use the defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EffectsDefinition {
+    pub debris_colors: Vec<String>,
+    pub smoke_color: String,
+    pub confetti_colors: Vec<String>,
+    pub particle_density: f32,
+    pub camera_shake: f32,
+}
+
+impl Default for EffectsDefinition {
+    fn default() -> Self {
+        Self {
+            debris_colors: ["FF6F00", "FFA000", "FFD54F", "5D4037"]
+                .map(String::from)
+                .to_vec(),
+            smoke_color: "616161AA".into(),
+            confetti_colors: ["F44336", "FFEB3B", "4CAF50", "2196F3", "9C27B0", "FF9800"]
+                .map(String::from)
+                .to_vec(),
+            particle_density: 1f32,
+            camera_shake: 12f32,
+        }
+    }
+}
+
+impl EffectsDefinition {
+    fn effect_assets(&self) -> Result<EffectAssets, ThemeError> {
+        Ok(EffectAssets {
+            debris_colors: parse_colors(&self.debris_colors)?,
+            smoke_color: parse_color(&self.smoke_color)?,
+            confetti_colors: parse_colors(&self.confetti_colors)?,
+            particle_density: self.particle_density.max(0f32),
+            camera_shake: self.camera_shake.max(0f32),
+        })
+    }
+}
+
+/// Serialized form of the `SoundAssets`, paths to `.ogg` files relative to the `assets`
+/// directory. Sounds left out use the built-in ones, there is no music unless a file is given.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SoundsDefinition {
+    pub uncover: Option<String>,
+    pub cascade: Option<String>,
+    pub flag: Option<String>,
+    pub unflag: Option<String>,
+    pub chord: Option<String>,
+    pub explosion: Option<String>,
+    pub win: Option<String>,
+    pub music: Option<String>,
+}
+
+impl SoundsDefinition {
+    fn sound_assets(&self, asset_server: &AssetServer) -> SoundAssets {
+        let load = |path: &Option<String>| path.as_ref().map(|path| asset_server.load(path));
+        SoundAssets {
+            uncover: load(&self.uncover),
+            cascade: load(&self.cascade),
+            flag: load(&self.flag),
+            unflag: load(&self.unflag),
+            chord: load(&self.chord),
+            explosion: load(&self.explosion),
+            win: load(&self.win),
+            music: load(&self.music),
+        }
+    }
+}
+
+/// Number colours of the colour-blind palettes for a theme, see `NumberPalette`. Palettes left
+/// out use the built-in colours, picked to contrast with the tiles.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PalettesDefinition {
+    pub deuteranopia: Option<Vec<String>>,
+    pub protanopia: Option<Vec<String>>,
+    pub tritanopia: Option<Vec<String>>,
+    pub monochrome: Option<Vec<String>>,
+}
+
+impl PalettesDefinition {
+    fn colors(&self, palette: NumberPalette) -> Option<&Vec<String>> {
+        match palette {
+            NumberPalette::Theme => None,
+            NumberPalette::Deuteranopia => self.deuteranopia.as_ref(),
+            NumberPalette::Protanopia => self.protanopia.as_ref(),
+            NumberPalette::Tritanopia => self.tritanopia.as_ref(),
+            NumberPalette::Monochrome => self.monochrome.as_ref(),
+        }
+    }
+}
+
+/// A theme file, describing everything needed to build the `BoardAssets`.
+#[derive(Debug, Clone, Serialize, Deserialize, Asset, TypePath)]
+pub struct ThemeDefinition {
+    pub label: String,
+    pub board_material: MaterialDefinition,
+    pub tile_material: MaterialDefinition,
+    pub covered_tile_material: MaterialDefinition,
+    pub mine_counter_font: String,
+    pub mine_counter_colors: Vec<String>,
+    pub flag_material: MaterialDefinition,
+    pub mine_material: MaterialDefinition,
+    /// Glyphs decorating the numbers 1 to 8, see `NumberDecoration::Glyphs`.
+    #[serde(default = "ThemeDefinition::default_number_glyphs")]
+    pub number_glyphs: Vec<String>,
+    #[serde(default = "ThemeDefinition::default_number_glyph_font")]
+    pub number_glyph_font: String,
+    #[serde(default)]
+    pub palettes: PalettesDefinition,
+    #[serde(default)]
+    pub effects: EffectsDefinition,
+    #[serde(default)]
+    pub sounds: SoundsDefinition,
+}
+
+impl Default for ThemeDefinition {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl ThemeDefinition {
+    /// Raised grey tiles with bevelled edges and the traditional number colors.
+    pub fn classic() -> Self {
+        Self {
+            label: "Classic".into(),
+            board_material: MaterialDefinition::color("808080"),
+            tile_material: MaterialDefinition::color("BDBDBD"),
+            covered_tile_material: MaterialDefinition::textured("FFFFFF", "sprites/Bevel.png"),
+            mine_counter_font: MINE_COUNTER_FONT.into(),
+            mine_counter_colors: [
+                "0000FF", "008000", "FF0000", "000080", "800000", "008080", "000000", "808080",
+            ]
+            .map(String::from)
+            .to_vec(),
+            flag_material: MaterialDefinition::textured("FFFFFF", "sprites/Flag.png"),
+            mine_material: MaterialDefinition::textured("FFFFFF", "sprites/Mine.png"),
+            number_glyphs: Self::default_number_glyphs(),
+            number_glyph_font: Self::default_number_glyph_font(),
+            palettes: PalettesDefinition::default(),
+            effects: EffectsDefinition {
+                smoke_color: "808080AA".into(),
+                confetti_colors: ["0000FF", "008000", "FF0000", "000080", "800000", "008080"]
+                    .map(String::from)
+                    .to_vec(),
+                ..default()
+            },
+            sounds: SoundsDefinition::default(),
+        }
+    }
+
+    /// Flat light tiles with saturated number colors.
+    pub fn light() -> Self {
+        Self {
+            label: "Light".into(),
+            board_material: MaterialDefinition::color("E0E0E0"),
+            tile_material: MaterialDefinition::color("FAFAFA"),
+            covered_tile_material: MaterialDefinition::color("B0BEC5"),
+            mine_counter_font: MINE_COUNTER_FONT.into(),
+            mine_counter_colors: [
+                "1E88E5", "43A047", "E53935", "3949AB", "8E24AA", "00897B", "212121", "757575",
+            ]
+            .map(String::from)
+            .to_vec(),
+            flag_material: MaterialDefinition::textured("FFFFFF", "sprites/Flag.png"),
+            mine_material: MaterialDefinition::textured("FFFFFF", "sprites/Mine.png"),
+            number_glyphs: Self::default_number_glyphs(),
+            number_glyph_font: Self::default_number_glyph_font(),
+            palettes: PalettesDefinition::default(),
+            effects: EffectsDefinition {
+                smoke_color: "9E9E9E88".into(),
+                ..default()
+            },
+            sounds: SoundsDefinition::default(),
+        }
+    }
+
+    /// The original look of the game.
+    pub fn dark() -> Self {
+        Self {
+            label: "Dark".into(),
+            board_material: MaterialDefinition::color("000000"),
+            tile_material: MaterialDefinition::color("404040"),
+            covered_tile_material: MaterialDefinition::color("808080"),
+            mine_counter_font: MINE_COUNTER_FONT.into(),
+            mine_counter_colors: ["FFFFFF", "00FF00", "FFFF00", "FFA500", "FF0000", "800080"]
+                .map(String::from)
+                .to_vec(),
+            flag_material: MaterialDefinition::textured("FFFFFF", "sprites/Flag.png"),
+            mine_material: MaterialDefinition::textured("FFFFFF", "sprites/Mine.png"),
+            number_glyphs: Self::default_number_glyphs(),
+            number_glyph_font: Self::default_number_glyph_font(),
+            palettes: PalettesDefinition::default(),
+            effects: EffectsDefinition::default(),
+            sounds: SoundsDefinition::default(),
+        }
+    }
+
+    /// White covers on black, with numbers from the Okabe-Ito palette so that neighbouring
+    /// counts stay distinguishable for colour-blind players.
+    pub fn high_contrast() -> Self {
+        Self {
+            label: "High contrast".into(),
+            board_material: MaterialDefinition::color("000000"),
+            tile_material: MaterialDefinition::color("141414"),
+            covered_tile_material: MaterialDefinition::color("FFFFFF"),
+            mine_counter_font: MINE_COUNTER_FONT.into(),
+            mine_counter_colors: [
+                "56B4E9", "E69F00", "F0E442", "CC79A7", "009E73", "D55E00", "FFFFFF", "999999",
+            ]
+            .map(String::from)
+            .to_vec(),
+            flag_material: MaterialDefinition::textured("FFFFFF", "sprites/Flag.png"),
+            mine_material: MaterialDefinition::textured("FFFFFF", "sprites/Mine.png"),
+            number_glyphs: Self::default_number_glyphs(),
+            number_glyph_font: Self::default_number_glyph_font(),
+            palettes: PalettesDefinition::default(),
+            effects: EffectsDefinition {
+                debris_colors: ["E69F00", "D55E00", "F0E442", "FFFFFF"]
+                    .map(String::from)
+                    .to_vec(),
+                smoke_color: "FFFFFF88".into(),
+                confetti_colors: ["56B4E9", "E69F00", "F0E442", "CC79A7", "009E73", "FFFFFF"]
+                    .map(String::from)
+                    .to_vec(),
+                // A gentler shake for players sensitive to motion
+                camera_shake: 4f32,
+                ..default()
+            },
+            sounds: SoundsDefinition::default(),
+        }
+    }
+
+    /// Builds the assets of the theme, with the number colours of the palette unless it is
+    /// `NumberPalette::Theme`.
+    pub fn board_assets(
+        &self,
+        asset_server: &AssetServer,
+        palette: NumberPalette,
+    ) -> Result<BoardAssets, ThemeError> {
+        let tile_material = self.tile_material.sprite_material(asset_server)?;
+        let mine_counter_colors = match self.palettes.colors(palette) {
+            Some(colors) => parse_colors(colors)?,
+            None => match palette.colors(is_dark(tile_material.color)) {
+                Some(colors) => parse_colors(&colors.map(String::from))?,
+                None => parse_colors(&self.mine_counter_colors)?,
+            },
+        };
+        Ok(BoardAssets {
+            label: self.label.clone(),
+            board_material: self.board_material.sprite_material(asset_server)?,
+            tile_material,
+            covered_tile_material: self.covered_tile_material.sprite_material(asset_server)?,
+            mine_counter_font: asset_server.load(&self.mine_counter_font),
+            mine_counter_colors,
+            number_glyphs: self.number_glyphs.clone(),
+            number_glyph_font: asset_server.load(&self.number_glyph_font),
+            flag_material: self.flag_material.sprite_material(asset_server)?,
+            mine_material: self.mine_material.sprite_material(asset_server)?,
+            effects: self.effects.effect_assets()?,
+            sounds: self.sounds.sound_assets(asset_server),
+        })
+    }
+
+    fn default_number_glyphs() -> Vec<String> {
+        NUMBER_GLYPHS.map(String::from).to_vec()
+    }
+
+    fn default_number_glyph_font() -> String {
+        NUMBER_GLYPH_FONT.into()
+    }
+}
+
+/// Whether light numbers contrast better with the color than dark ones.
+fn is_dark(color: Color) -> bool {
+    let [red, green, blue, _] = color.as_rgba_f32();
+    0.2126 * red + 0.7152 * green + 0.0722 * blue < 0.5
+}
+
+fn parse_color(value: &str) -> Result<Color, ThemeError> {
+    Color::hex(value).map_err(|_| ThemeError::Color(value.to_string()))
+}
+
+fn parse_colors(values: &[String]) -> Result<Vec<Color>, ThemeError> {
+    values.iter().map(|color| parse_color(color)).collect()
+}
+
+/// Themes available to the player and the one currently in use.
+///
+/// The built-in themes come first, followed by the theme files. Themes are regular assets, so
+/// they are reloaded whenever their files change when the `hot_reload` feature is enabled.
+#[derive(Debug, Clone, Resource)]
+pub struct Themes {
+    themes: Vec<Handle<ThemeDefinition>>,
+    selected: usize,
+    light: usize,
+    dark: usize,
+    /// Whether the light or dark theme is picked to match the OS window theme. Turned off once
+    /// the player selects a theme.
+    pub follow_window_theme: bool,
+}
+
+impl Themes {
+    /// Adds the built-in themes and loads every theme file from the themes directory, ordered by
+    /// file name.
+    pub fn load(asset_server: &AssetServer, definitions: &mut Assets<ThemeDefinition>) -> Self {
+        let mut themes = vec![
+            definitions.add(ThemeDefinition::classic()),
+            definitions.add(ThemeDefinition::light()),
+            definitions.add(ThemeDefinition::dark()),
+            definitions.add(ThemeDefinition::high_contrast()),
+        ];
+        let (light, dark) = (1, 2);
+
+        let directory = persistence::assets_directory().join(THEMES_DIRECTORY);
+        let mut file_names: Vec<String> = match std::fs::read_dir(&directory) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .filter(|file_name| file_name.ends_with(THEME_FILE_EXTENSION))
+                .collect(),
+            Err(e) => {
+                warn!("Failed to read themes from {}: {}", directory.display(), e);
+                Vec::new()
+            }
+        };
+        file_names.sort();
+
+        themes.extend(
+            file_names
+                .iter()
+                .map(|file_name| asset_server.load(format!("{}/{}", THEMES_DIRECTORY, file_name))),
+        );
+
+        Self {
+            themes,
+            selected: dark,
+            light,
+            dark,
+            follow_window_theme: true,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.themes.len()
+    }
+
+    pub fn current(&self) -> &Handle<ThemeDefinition> {
+        &self.themes[self.selected]
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % self.themes.len();
+        self.follow_window_theme = false;
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = (self.selected + self.themes.len() - 1) % self.themes.len();
+        self.follow_window_theme = false;
+    }
+
+    /// Index of the built-in theme matching an OS window theme.
+    pub fn index_for(&self, window_theme: WindowTheme) -> usize {
+        match window_theme {
+            WindowTheme::Light => self.light,
+            WindowTheme::Dark => self.dark,
+        }
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.themes.len() - 1);
+    }
+}
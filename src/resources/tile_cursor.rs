@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+use crate::components::Coordinates;
+use crate::resources::Board;
+
+/// The tile selected for keyboard play. Only shown once the keyboard is used, moving the mouse
+/// hides it again.
+#[derive(Debug, Default, Copy, Clone, Resource)]
+pub struct TileCursor {
+    pub coordinates: Coordinates,
+    pub visible: bool,
+}
+
+impl TileCursor {
+    /// Moves one tile in a direction, skipping the holes of shaped boards. Stays in place at the
+    /// edge of the board.
+    pub fn step(&mut self, board: &Board, direction: IVec2) {
+        if let Some(coordinates) = Self::walk(board, self.coordinates, direction).next() {
+            self.coordinates = coordinates;
+        }
+    }
+
+    /// Moves to the last tile of the board in a direction.
+    pub fn jump_to_edge(&mut self, board: &Board, direction: IVec2) {
+        if let Some(coordinates) = Self::walk(board, self.coordinates, direction).last() {
+            self.coordinates = coordinates;
+        }
+    }
+
+    /// Moves to the next covered tile that is not flagged, in reading order and wrapping around
+    /// the board. Tiles next to uncovered ones are preferred, as that is where the game continues.
+    pub fn jump_to_unresolved(&mut self, board: &Board, forward: bool) {
+        let tile_map = &board.tile_map;
+        let (width, height) = (tile_map.width() as usize, tile_map.height() as usize);
+        let count = width * height;
+        if count == 0 {
+            return;
+        }
+
+        // Rows are read from the top of the board, which has the highest y
+        let index_of = |coordinates: Coordinates| {
+            (height - 1 - coordinates.y as usize) * width + coordinates.x as usize
+        };
+        let coordinates_at = |index: usize| Coordinates {
+            x: (index % width) as u16,
+            y: (height - 1 - index / width) as u16,
+        };
+
+        let start = index_of(self.coordinates);
+        let candidates: Vec<Coordinates> = (1..=count)
+            .map(|offset| {
+                if forward {
+                    (start + offset) % count
+                } else {
+                    (start + count - offset % count) % count
+                }
+            })
+            .map(coordinates_at)
+            .filter(|coordinates| {
+                board.covered_tiles.contains_key(coordinates)
+                    && !board.marked_tiles.contains(coordinates)
+            })
+            .collect();
+
+        let next_to_uncovered = candidates.iter().find(|coordinates| {
+            tile_map.safe_square_at(**coordinates).any(|neighbour| {
+                tile_map.has_tile_at(neighbour) && !board.covered_tiles.contains_key(&neighbour)
+            })
+        });
+        if let Some(coordinates) = next_to_uncovered.or(candidates.first()) {
+            self.coordinates = *coordinates;
+        }
+    }
+
+    /// Places the cursor on the tile closest to the center of the board.
+    pub fn center(&mut self, board: &Board) {
+        let tile_map = &board.tile_map;
+        let center = Vec2::new(tile_map.width() as f32, tile_map.height() as f32) / 2f32;
+        let closest = (0..tile_map.height())
+            .flat_map(|y| (0..tile_map.width()).map(move |x| Coordinates { x, y }))
+            .filter(|coordinates| tile_map.has_tile_at(*coordinates))
+            .min_by(|a, b| {
+                let distance = |c: &Coordinates| {
+                    (Vec2::new(c.x as f32, c.y as f32) + 0.5 - center).length_squared()
+                };
+                distance(a).total_cmp(&distance(b))
+            });
+        if let Some(coordinates) = closest {
+            self.coordinates = coordinates;
+        }
+    }
+
+    /// Tiles in a direction, in order, skipping holes.
+    fn walk(
+        board: &Board,
+        start: Coordinates,
+        direction: IVec2,
+    ) -> impl Iterator<Item = Coordinates> + '_ {
+        let tile_map = &board.tile_map;
+        let size = IVec2::new(tile_map.width() as i32, tile_map.height() as i32);
+        let start = IVec2::new(start.x as i32, start.y as i32);
+
+        (1..)
+            .map(move |distance| start + direction * distance)
+            .take_while(move |position| {
+                direction != IVec2::ZERO
+                    && position.cmpge(IVec2::ZERO).all()
+                    && position.cmplt(size).all()
+            })
+            .map(|position| Coordinates {
+                x: position.x as u16,
+                y: position.y as u16,
+            })
+            .filter(|coordinates| tile_map.has_tile_at(*coordinates))
+    }
+}
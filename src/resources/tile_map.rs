@@ -1,9 +1,11 @@
 use std::ops::{Deref, DerefMut};
 
+use bevy::log::warn;
 use rand::{thread_rng, Rng};
 
 use crate::components::Coordinates;
 use crate::resources::tile::Tile;
+use crate::resources::BoardMask;
 
 /// An array of tuples representing the coordinates of the neighbors of a cell in a grid.
 /// The array contains the coordinates of the neighbors in the following order:
@@ -39,10 +41,16 @@ pub struct TileMap {
     width: u16,
     height: u16,
     map: Vec<Vec<Tile>>,
+    mask: BoardMask,
 }
 
 impl TileMap {
     pub fn new_empty(width: u16, height: u16) -> Self {
+        Self::new_masked(BoardMask::rectangle(width, height))
+    }
+
+    pub fn new_masked(mask: BoardMask) -> Self {
+        let (width, height) = (mask.width(), mask.height());
         let map = (0..height)
             .map(|_| (0..width).map(|_| Tile::Empty).collect())
             .collect();
@@ -52,30 +60,68 @@ impl TileMap {
             width,
             height,
             map,
+            mask,
         }
     }
 
+    /// Builds a map with mines at the given coordinates. Coordinates outside the mask are ignored.
+    pub fn from_mines(mask: BoardMask, mines: &[Coordinates]) -> Self {
+        let mut tile_map = Self::new_masked(mask);
+        for coords in mines {
+            if tile_map.has_tile_at(*coords) && !tile_map.is_mine_at(*coords) {
+                tile_map[coords.y as usize][coords.x as usize] = Tile::Mine;
+                tile_map.mine_count = tile_map.mine_count.saturating_add(1);
+            }
+        }
+
+        tile_map.update_mine_neighbors();
+        tile_map
+    }
+
     pub fn set_mines(&mut self, mine_count: u16) {
-        self.mine_count = mine_count;
-        let mut remaining_mines = mine_count;
-        let mut rng = thread_rng();
+        self.set_mines_with_rng(mine_count, &mut thread_rng());
+    }
 
-        while remaining_mines > 0 {
-            let (x, y) = (
-                rng.gen_range(0..self.width) as usize,
-                rng.gen_range(0..self.height) as usize,
+    /// Places mines using the given random number generator, so seeded generators always produce
+    /// the same board.
+    pub fn set_mines_with_rng(&mut self, mine_count: u16, rng: &mut impl Rng) {
+        // Always leave at least one tile free, otherwise the placement loop below would never end
+        let max_mines = self.tile_count().saturating_sub(1).min(u16::MAX as usize) as u16;
+        if mine_count > max_mines {
+            warn!(
+                "Requested {} mines, but the board can hold only {}",
+                mine_count, max_mines
             );
+        }
+
+        self.mine_count = mine_count.min(max_mines);
+        let mut remaining_mines = self.mine_count;
 
+        while remaining_mines > 0 {
+            let coords = Coordinates {
+                x: rng.gen_range(0..self.width),
+                y: rng.gen_range(0..self.height),
+            };
+
+            if !self.has_tile_at(coords) {
+                continue;
+            }
+
+            let (x, y) = (coords.x as usize, coords.y as usize);
             if let Tile::Empty = self[y][x] {
                 self[y][x] = Tile::Mine;
                 remaining_mines -= 1;
             }
         }
 
+        self.update_mine_neighbors();
+    }
+
+    fn update_mine_neighbors(&mut self) {
         for y in 0..self.height {
             for x in 0..self.width {
                 let coords = Coordinates { x, y };
-                if self.is_mine_at(coords) {
+                if !self.has_tile_at(coords) || self.is_mine_at(coords) {
                     continue;
                 }
 
@@ -97,6 +143,11 @@ impl TileMap {
             .map(move |tuple| coordinates + tuple)
     }
 
+    /// Returns `false` for coordinates outside the map and for holes cut out by the board mask.
+    pub fn has_tile_at(&self, coordinates: Coordinates) -> bool {
+        self.mask.contains(coordinates)
+    }
+
     pub fn is_mine_at(&self, coordinates: Coordinates) -> bool {
         if coordinates.x >= self.width || coordinates.y >= self.height {
             return false;
@@ -127,6 +178,20 @@ impl TileMap {
         self.mine_count
     }
 
+    pub fn mines(&self) -> impl Iterator<Item = Coordinates> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| Coordinates { x, y }))
+            .filter(|coords| self.is_mine_at(*coords))
+    }
+
+    pub fn tile_count(&self) -> usize {
+        self.mask.tile_count()
+    }
+
+    pub fn is_rectangle(&self) -> bool {
+        self.mask.is_rectangle()
+    }
+
     #[cfg(feature = "debug")]
     pub fn console_output(&self) -> String {
         let mut buffer: String = format!(
@@ -162,3 +227,41 @@ impl DerefMut for TileMap {
         &mut self.map
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn donut() -> BoardMask {
+        BoardMask::from_text("#####\n##.##\n#...#\n##.##\n#####").unwrap()
+    }
+
+    #[test]
+    fn mines_never_land_in_holes() {
+        let mask = donut();
+        for _ in 0..20 {
+            let mut tile_map = TileMap::new_masked(mask.clone());
+            tile_map.set_mines(10);
+
+            assert_eq!(tile_map.mine_count(), 10);
+            assert_eq!(tile_map.mines().count(), 10);
+            assert!(tile_map
+                .mines()
+                .all(|coordinates| mask.contains(coordinates)));
+        }
+    }
+
+    #[test]
+    fn mines_leave_one_tile_free() {
+        let mask = donut();
+        let mut tile_map = TileMap::new_masked(mask.clone());
+        tile_map.set_mines(u16::MAX);
+
+        assert_eq!(tile_map.tile_count(), mask.tile_count());
+        assert_eq!(tile_map.mine_count() as usize, mask.tile_count() - 1);
+        assert_eq!(tile_map.mines().count(), mask.tile_count() - 1);
+        assert!(tile_map
+            .mines()
+            .all(|coordinates| mask.contains(coordinates)));
+    }
+}
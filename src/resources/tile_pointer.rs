@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::components::Coordinates;
+
+/// Tile under the mouse and the tile held down with a mouse button, for hover and press
+/// feedback. Uncovering and chording trigger when the button is released over the pressed tile.
+#[derive(Debug, Default, Copy, Clone, Resource)]
+pub struct TilePointer {
+    pub hovered: Option<Coordinates>,
+    pub pressed: Option<TilePress>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct TilePress {
+    pub coordinates: Coordinates,
+    pub button: MouseButton,
+    /// Pressing an uncovered number, or with the chord button, presses its covered neighbours.
+    pub chord: bool,
+}
+
+impl TilePointer {
+    /// The press, while the mouse is still over the pressed tile. Releasing the button
+    /// elsewhere cancels it.
+    pub fn armed(&self) -> Option<TilePress> {
+        self.pressed
+            .filter(|press| self.hovered == Some(press.coordinates))
+    }
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+/// What tapping a tile does on a touch screen, long pressing does the other.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Resource)]
+pub enum TouchMode {
+    #[default]
+    Uncover,
+    Flag,
+}
+
+impl TouchMode {
+    pub fn toggle(&mut self) {
+        *self = match self {
+            TouchMode::Uncover => TouchMode::Flag,
+            TouchMode::Flag => TouchMode::Uncover,
+        };
+    }
+
+    /// Whether a tap, or a long press otherwise, flags the tile.
+    pub fn flags(&self, long_press: bool) -> bool {
+        (*self == TouchMode::Flag) != long_press
+    }
+}
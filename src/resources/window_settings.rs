@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use bevy::window::{PresentMode, WindowMode};
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+
+const WINDOW_SETTINGS_FILE_NAME: &str = "window.ron";
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FullscreenMode {
+    /// A borderless window covering the monitor, switching is fast and keeps the desktop
+    /// resolution.
+    #[default]
+    Borderless,
+    /// Exclusive fullscreen at the best video mode of the monitor.
+    Exclusive,
+}
+
+/// Window mode, vsync, monitor and the last windowed size and position, persisted between
+/// sessions.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Resource)]
+#[serde(default)]
+pub struct WindowSettings {
+    pub fullscreen: bool,
+    pub fullscreen_mode: FullscreenMode,
+    pub vsync: bool,
+    /// Index of the monitor, the primary monitor is used when not set.
+    pub monitor: Option<usize>,
+    /// Logical size of the window when not in fullscreen.
+    pub size: (f32, f32),
+    /// Physical position of the top-left corner when not in fullscreen, centered on the monitor
+    /// when not set.
+    pub position: Option<(i32, i32)>,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            fullscreen: false,
+            fullscreen_mode: FullscreenMode::default(),
+            vsync: true,
+            monitor: None,
+            size: (800f32, 800f32),
+            position: None,
+        }
+    }
+}
+
+impl WindowSettings {
+    pub fn load() -> Self {
+        persistence::load(WINDOW_SETTINGS_FILE_NAME).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        persistence::save(WINDOW_SETTINGS_FILE_NAME, self);
+    }
+
+    pub fn window_mode(&self) -> WindowMode {
+        match (self.fullscreen, self.fullscreen_mode) {
+            (false, _) => WindowMode::Windowed,
+            (true, FullscreenMode::Borderless) => WindowMode::BorderlessFullscreen,
+            (true, FullscreenMode::Exclusive) => WindowMode::Fullscreen,
+        }
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        if self.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        }
+    }
+
+    pub fn monitor_selection(&self) -> MonitorSelection {
+        match self.monitor {
+            Some(index) => MonitorSelection::Index(index),
+            None => MonitorSelection::Primary,
+        }
+    }
+
+    /// Position used when creating the window.
+    pub fn window_position(&self) -> WindowPosition {
+        match self.position {
+            Some((x, y)) => WindowPosition::At(IVec2::new(x, y)),
+            None => WindowPosition::Centered(self.monitor_selection()),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+
+use crate::persistence;
+use crate::resources::{Action, Board, BoardFormat, InputMap};
+
+const EXPORT_DIRECTORY: &str = "exports";
+const EXPORT_EXTENSIONS: [&str; 2] = ["txt", "mbf"];
+
+/// This system exports the current board layout when pressing the button bound to
+/// `Action::ExportBoard` (E by default).
+/// The layout is written in every supported format to the `exports` directory in the platform
+/// data directory, as `board-<timestamp>` so earlier exports are never overwritten.
+pub fn export_board(
+    input_map: Res<InputMap>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<MouseButton>>,
+    board: Res<Board>,
+) {
+    if !input_map.just_pressed(Action::ExportBoard, &keys, &buttons) {
+        return;
+    }
+
+    info!(
+        "[{}] pressed. Exporting the board layout",
+        input_map.label(Action::ExportBoard)
+    );
+    let directory = persistence::data_directory().join(EXPORT_DIRECTORY);
+    if let Err(e) = std::fs::create_dir_all(&directory) {
+        warn!(
+            "Failed to create the export directory {}: {}",
+            directory.display(),
+            e
+        );
+        return;
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    // Numbered when several boards are exported within the same second
+    let paths = (1..)
+        .map(|index| match index {
+            1 => format!("board-{}", timestamp),
+            _ => format!("board-{}-{}", timestamp, index),
+        })
+        .map(|name| {
+            EXPORT_EXTENSIONS.map(|extension| directory.join(format!("{}.{}", name, extension)))
+        })
+        .find(|paths| paths.iter().all(|path| !path.exists()))
+        .unwrap();
+
+    for path in paths {
+        match BoardFormat::save(&path, &board.tile_map) {
+            Ok(()) => info!("Board exported to {}", path.display()),
+            Err(e) => warn!("Failed to export the board to {}: {}", path.display(), e),
+        }
+    }
+}
@@ -1,42 +1,111 @@
-use bevy::input::mouse::MouseButtonInput;
-use bevy::input::ButtonState;
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
 use crate::plugins::{TileMarkEvent, TileTriggerEvent};
-use crate::resources::Board;
+use crate::resources::{
+    Action, Board, GameplaySettings, InputBinding, InputMap, TilePointer, TilePress,
+};
+use crate::systems::Minesweeper2dCamera;
 
-pub fn handle_mouse_input(
+/// Keeps track of the tile under the mouse.
+pub fn track_mouse_tile(
     window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Minesweeper2dCamera>>,
     board: Res<Board>,
-    mut button_event_reader: EventReader<MouseButtonInput>,
+    mut pointer: ResMut<TilePointer>,
+) {
+    let window = window_query.single();
+    let (camera, camera_transform) = camera_query.single();
+
+    let hovered = window
+        .cursor_position()
+        .and_then(|position| board.mouse_position(camera, camera_transform, position));
+    if pointer.hovered != hovered {
+        pointer.hovered = hovered;
+    }
+}
+
+/// Handles the mouse buttons bound to `Action::Uncover`, `Action::Flag` and `Action::Chord` on
+/// the tile under the cursor. Keys bound to them act on the keyboard cursor instead.
+///
+/// Flags are toggled as soon as the button is pressed. Uncovering and chording press the tile
+/// down and trigger when the button is released over it, releasing it elsewhere cancels.
+/// Presses that would chord are ignored when `GameplaySettings::chord_mode` disallows it.
+pub fn handle_mouse_input(
+    board: Res<Board>,
+    gameplay: Res<GameplaySettings>,
+    input_map: Res<InputMap>,
+    buttons: Res<Input<MouseButton>>,
+    mut pointer: ResMut<TilePointer>,
     mut tile_trigger_event_writer: EventWriter<TileTriggerEvent>,
     mut tile_mark_event_writer: EventWriter<TileMarkEvent>,
 ) {
-    let window = window_query.single();
+    if let Some(coordinates) = pointer.hovered {
+        if buttons.any_just_pressed(input_map.buttons(Action::Flag)) {
+            info!(
+                "[{}] clicked, trying to mark tile on {}",
+                input_map.label(Action::Flag),
+                coordinates
+            );
+            tile_mark_event_writer.send(TileMarkEvent(coordinates));
+        }
 
-    for event in button_event_reader.read() {
-        if let ButtonState::Pressed = event.state {
-            if let Some(click_position) = window.cursor_position() {
-                if let Some(tile_coordinates) = board.mouse_position(window, click_position) {
-                    match event.button {
-                        MouseButton::Left => {
-                            info!(
-                                "LMB clicked, trying to uncover tile on {}",
-                                tile_coordinates
-                            );
-                            tile_trigger_event_writer.send(TileTriggerEvent {
-                                coordinates: tile_coordinates,
-                            });
-                        }
-                        MouseButton::Right => {
-                            info!("RMB clicked, trying to mark tile on {}", tile_coordinates);
-                            tile_mark_event_writer.send(TileMarkEvent(tile_coordinates));
-                        }
-                        _ => {}
-                    }
-                }
+        for action in [Action::Uncover, Action::Chord] {
+            let Some(button) = input_map
+                .buttons(action)
+                .find(|button| buttons.just_pressed(*button))
+            else {
+                continue;
+            };
+            let covered = board.covered_tiles.contains_key(&coordinates);
+            if !covered && !gameplay.allows_chord(action == Action::Chord) {
+                info!(
+                    "[{}] clicked, chording with it is disabled",
+                    InputBinding::Mouse(button)
+                );
+                continue;
             }
+            pointer.pressed = Some(TilePress {
+                coordinates,
+                button,
+                chord: action == Action::Chord || !covered,
+            });
         }
     }
+
+    let Some(press) = pointer.pressed else {
+        return;
+    };
+    if buttons.pressed(press.button) {
+        return;
+    }
+    pointer.pressed = None;
+
+    if pointer.hovered != Some(press.coordinates) {
+        info!(
+            "[{}] released outside of {}, cancelling",
+            InputBinding::Mouse(press.button),
+            press.coordinates
+        );
+    } else if !press.chord {
+        info!(
+            "[{}] released, trying to uncover tile on {}",
+            InputBinding::Mouse(press.button),
+            press.coordinates
+        );
+        tile_trigger_event_writer.send(TileTriggerEvent {
+            coordinates: press.coordinates,
+        });
+    } else if board.covered_tiles.contains_key(&press.coordinates) {
+        warn!("Only uncovered numbers can be chorded");
+    } else {
+        info!(
+            "[{}] released, trying to chord on {}",
+            InputBinding::Mouse(press.button),
+            press.coordinates
+        );
+        tile_trigger_event_writer.send(TileTriggerEvent {
+            coordinates: press.coordinates,
+        });
+    }
 }
@@ -1,20 +1,36 @@
 use bevy::prelude::*;
 
 use crate::{
+    components::BoardElement,
     plugins::TileMarkEvent,
-    resources::{Board, BoardAssets},
+    resources::{Board, BoardAssets, BoardOptions, GameplaySettings, TileMark},
 };
 
 pub fn mark_tiles(
     mut commands: Commands,
     mut board: ResMut<Board>,
     board_assets: Res<BoardAssets>,
+    board_options: Option<Res<BoardOptions>>,
+    gameplay: Res<GameplaySettings>,
     mut tile_mark_event_reader: EventReader<TileMarkEvent>,
     query: Query<&Children>,
 ) {
+    let tile_padding = board_options.map_or(0f32, |options| options.tile_padding);
+
     for event in tile_mark_event_reader.read() {
-        if let Some((entity, mark)) = board.try_toggle_mark(&event.0) {
-            if mark {
+        let Some((entity, mark)) = board.try_toggle_mark(&event.0, gameplay.question_marks) else {
+            continue;
+        };
+
+        // The previous mark is replaced
+        if let Ok(children) = query.get(entity) {
+            for child in children.iter() {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+
+        match mark {
+            TileMark::Flag => {
                 commands.entity(entity).with_children(|parent| {
                     parent
                         .spawn(SpriteBundle {
@@ -27,20 +43,29 @@ pub fn mark_tiles(
                             transform: Transform::from_xyz(0f32, 0f32, 1f32),
                             ..default()
                         })
-                        .insert(Name::new("Flag"));
+                        .insert(Name::new("Flag"))
+                        .insert(BoardElement::Flag);
                 });
-            } else {
-                let children = match query.get(entity) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        error!("Failed to retrieve flag entity components: {}", e);
-                        continue;
-                    }
+            }
+            TileMark::QuestionMark => {
+                let text_style = TextStyle {
+                    font: board_assets.mine_counter_font.clone(),
+                    font_size: board.tile_size - tile_padding,
+                    color: board_assets.question_mark_color(),
                 };
-                for child in children.iter() {
-                    commands.entity(*child).despawn_recursive();
-                }
+                commands.entity(entity).with_children(|parent| {
+                    parent
+                        .spawn(Text2dBundle {
+                            text: Text::from_section("?", text_style)
+                                .with_alignment(TextAlignment::Center),
+                            transform: Transform::from_xyz(0f32, 0f32, 1f32),
+                            ..default()
+                        })
+                        .insert(Name::new("Question Mark"))
+                        .insert(BoardElement::QuestionMark);
+                });
             }
+            TileMark::Unmarked => {}
         }
     }
 }
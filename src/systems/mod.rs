@@ -1,14 +1,28 @@
 pub use camera::setup_2d_camera;
+pub use camera::Minesweeper2dCamera;
+pub use export::export_board;
 pub use input_handler::handle_mouse_input;
+pub use input_handler::track_mouse_tile;
 pub use mark::mark_tiles;
+pub use timer::stop_game_timer;
+pub use timer::tick_game_timer;
+pub use ui::text_style;
+pub use ui::BUTTON_COLOR;
+pub use ui::BUTTON_HOVERED_COLOR;
+pub use ui::MENU_BACKGROUND_COLOR;
+pub use ui::OVERLAY_COLOR;
+pub use ui::UI_FONT_PATH;
 pub use uncover::trigger_event_handler;
 pub use uncover::uncover_tiles;
 pub use vsync::toggle_vsync;
 pub use window_visibility::make_window_visible_after_startup;
 
 mod camera;
+mod export;
 mod input_handler;
 mod mark;
+mod timer;
+mod ui;
 mod uncover;
 mod vsync;
 mod window_visibility;
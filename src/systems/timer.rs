@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use crate::plugins::{BoardCompletedEvent, MineExplosionEvent};
+use crate::resources::GameTimer;
+
+pub fn tick_game_timer(time: Res<Time>, mut game_timer: ResMut<GameTimer>) {
+    game_timer.tick(time.delta());
+}
+
+pub fn stop_game_timer(
+    mut game_timer: ResMut<GameTimer>,
+    mut board_completed_event_reader: EventReader<BoardCompletedEvent>,
+    mut mine_explosion_event_reader: EventReader<MineExplosionEvent>,
+) {
+    let completed = board_completed_event_reader.read().count() > 0;
+    let exploded = mine_explosion_event_reader.read().count() > 0;
+
+    if (completed || exploded) && !game_timer.is_finished() {
+        game_timer.finish();
+        info!(
+            "Game finished after {:.2} seconds",
+            game_timer.elapsed().as_secs_f32()
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+/// Font of the menus and overlays, `LocalizationPlugin` falls back to other fonts for glyphs it
+/// lacks.
+pub const UI_FONT_PATH: &str = "fonts/symtext/Symtext.ttf";
+/// Background of the full screen menus.
+pub const MENU_BACKGROUND_COLOR: Color = Color::rgb(0.1, 0.1, 0.1);
+pub const BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+pub const BUTTON_HOVERED_COLOR: Color = Color::rgb(0.35, 0.35, 0.35);
+/// Translucent background of hints and counters drawn over the board.
+pub const OVERLAY_COLOR: Color = Color::rgba(0f32, 0f32, 0f32, 0.6);
+
+pub fn text_style(font: &Handle<Font>, font_size: f32, color: Color) -> TextStyle {
+    TextStyle {
+        font: font.clone(),
+        font_size,
+        color,
+    }
+}
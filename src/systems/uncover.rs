@@ -13,6 +13,10 @@ pub fn trigger_event_handler(
     for trigger_event in tile_trigger_event_reader.read() {
         if let Some(entity) = board.tile_to_uncover(&trigger_event.coordinates) {
             commands.entity(*entity).insert(Uncover);
+        } else {
+            for entity in board.chord_tiles(trigger_event.coordinates) {
+                commands.entity(entity).insert(Uncover);
+            }
         }
     }
 }
@@ -48,7 +52,9 @@ pub fn uncover_tiles(
 
         if mine.is_some() {
             info!("Boom!");
-            mine_explosion_event_writer.send(MineExplosionEvent);
+            mine_explosion_event_writer.send(MineExplosionEvent {
+                coordinates: *coordinates,
+            });
         } else if mine_counter.is_none() {
             for entity in board.adjacent_covered_tiles(*coordinates) {
                 commands.entity(entity).insert(Uncover);
@@ -1,21 +1,23 @@
 use bevy::prelude::*;
-use bevy::window::PresentMode;
 
-/// This system toggles the vsync mode when pressing the button V.
-/// You'll see FPS increase displayed in the console (if debug feature is enabled)
-pub fn toggle_vsync(input: Res<Input<KeyCode>>, mut windows: Query<&mut Window>) {
-    if input.just_pressed(KeyCode::V) {
-        let mut window = windows.single_mut();
+use crate::resources::{Action, InputMap, WindowSettings};
 
-        window.present_mode = if matches!(window.present_mode, PresentMode::AutoVsync) {
-            PresentMode::AutoNoVsync
-        } else {
-            PresentMode::AutoVsync
-        };
+/// This system toggles the vsync mode when pressing the button bound to
+/// `Action::ToggleVsync` (V by default). The `WindowSettingsPlugin` applies it to the window.
+/// You'll see FPS increase displayed in the console (if debug feature is enabled)
+pub fn toggle_vsync(
+    input_map: Res<InputMap>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<MouseButton>>,
+    mut settings: ResMut<WindowSettings>,
+) {
+    if input_map.just_pressed(Action::ToggleVsync, &keys, &buttons) {
+        settings.vsync = !settings.vsync;
 
         info!(
-            "[V] key pressed. Changing VSync mode to: {:?}",
-            window.present_mode
+            "[{}] pressed. Changing VSync mode to: {:?}",
+            input_map.label(Action::ToggleVsync),
+            settings.present_mode()
         );
     }
 }